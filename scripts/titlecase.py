@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+# Generates src/titlecase.rs, the characters whose titlecase mapping differs
+# from their uppercase mapping, from the Unicode character database (the
+# simple mappings of UnicodeData.txt and the full mappings of
+# SpecialCasing.txt) as bundled with Python.
+#
+#   python3 scripts/titlecase.py > src/titlecase.rs
+
+import sys
+import unicodedata
+
+
+def escape(string):
+    return ''.join(c if c.isprintable() and c not in '"\\' else '\\u{%x}' % ord(c) for c in string)
+
+
+entries = []
+for code in range(sys.maxunicode + 1):
+    c = chr(code)
+    if 0xd800 <= code <= 0xdfff:
+        continue
+    title = c.title()
+    if title != c.upper():
+        entries.append((code, title))
+
+print('''//! The titlecase mappings of characters which are not written like their
+//! uppercase mappings at the start of a word, like the digraph “ǅ” or the
+//! ligature “ﬁ.”
+//!
+//! Generated by `scripts/titlecase.py` from the Unicode %s character
+//! database. Do not edit by hand.
+
+use std::char::ToUppercase;
+use std::str::Chars;
+
+/// Converts a character into its titlecase mapping, which may be more than
+/// one character.
+#[inline]
+pub(crate) fn to_titlecase(c: char) -> ToTitlecase {
+  match TITLECASE.binary_search_by_key(&c, |&(key, _)| key) {
+    Ok(i) => ToTitlecase::Mapped(TITLECASE[i].1.chars()),
+    Err(_) => ToTitlecase::Upper(c.to_uppercase()),
+  }
+}
+
+/// An iterator over the titlecase mapping of a character.
+#[derive(Clone, Debug)]
+pub(crate) enum ToTitlecase {
+  /// A mapping from the table.
+  Mapped(Chars<'static>),
+  /// A character whose titlecase is its uppercase.
+  Upper(ToUppercase),
+}
+
+impl Iterator for ToTitlecase {
+  type Item = char;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match *self {
+      ToTitlecase::Mapped(ref mut chars) => chars.next(),
+      ToTitlecase::Upper(ref mut upper) => upper.next(),
+    }
+  }
+}
+
+/// Characters and their titlecase mappings, sorted by character.
+const TITLECASE: &[(char, &str)] = &[''' % unicodedata.unidata_version)
+for code, title in entries:
+    print('  (\'\\u{%x}\', "%s"),' % (code, escape(title)))
+print('];')
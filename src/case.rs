@@ -15,8 +15,11 @@
 //! assert_eq!(to_camel_case("Hello World"), "helloWorld".to_owned());
 //! ```
 
-use std::char::ToUppercase;
-use std::iter::Peekable;
+use std::iter;
+use inflector::{capitalize, default_inflector, language, proper_noun};
+use titlecase::{to_titlecase, ToTitlecase};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Converts any case into lower case ignoring separators.
 ///
@@ -33,12 +36,10 @@ use std::iter::Peekable;
 /// assert_eq!(to_lower_case("Hello-World"), "hello-world".to_owned());
 /// assert_eq!(to_lower_case("hello_world"), "hello_world".to_owned());
 /// assert_eq!(to_lower_case("HELLO_WORLD"), "hello_world".to_owned());
+/// assert_eq!(to_lower_case("ΟΔΟΣ ΣΟΦΙΑΣ"), "οδος σοφιας".to_owned());
 /// ```
 pub fn to_lower_case(string: &str) -> String {
-  string
-  .chars()
-  .flat_map(char::to_lowercase)
-  .collect()
+  string.to_lowercase()
 }
 
 /// Check to see if a string is completely lower case.
@@ -118,12 +119,7 @@ pub fn is_upper_case(string: &str) -> bool {
 /// assert_eq!(to_sentence_case("HELLO_WORLD"), sentence);
 /// ```
 pub fn to_sentence_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, ' '))
-  .break_camel(' ')
-  .flat_map(char::to_lowercase)
-  .collect()
+  separate(string, ' ').to_lowercase()
 }
 
 /// Check to see if a string is sentence case.
@@ -145,6 +141,8 @@ pub fn is_sentence_case(string: &str) -> bool {
 }
 
 /// Converts any case into title case where *every* word is capitalized.
+/// Proper nouns of the English rules like “GitHub” keep their canonical
+/// spelling. Use an `Inflector` to register acronyms and more proper nouns.
 ///
 /// # Example
 /// ```rust
@@ -159,15 +157,18 @@ pub fn is_sentence_case(string: &str) -> bool {
 /// assert_eq!(to_title_case("Hello-World"), title);
 /// assert_eq!(to_title_case("hello_world"), title);
 /// assert_eq!(to_title_case("HELLO_WORLD"), title);
+/// assert_eq!(to_title_case("iphone sync"), "iPhone Sync".to_owned());
+/// assert_eq!(to_title_case("iPhone Sync"), "iPhone Sync".to_owned());
+/// assert_eq!(to_title_case("ǆungla ǉubav"), "ǅungla ǈubav".to_owned());
+/// assert_eq!(to_title_case("e\u{301}cole"), "E\u{301}cole".to_owned());
 /// ```
 pub fn to_title_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, ' '))
-  .break_camel(' ')
-  .flat_map(char::to_lowercase)
-  .capitalize_words()
-  .collect()
+  separate_keeping(string, ' ', is_proper_noun)
+  .to_lowercase()
+  .split(' ')
+  .map(capitalize_title_word)
+  .collect::<Vec<_>>()
+  .join(" ")
 }
 
 /// Check to see if a string is Title Case.
@@ -203,10 +204,12 @@ pub fn is_title_case(string: &str) -> bool {
 /// assert_eq!(to_camel_case("Hello-World"), camel);
 /// assert_eq!(to_camel_case("hello_world"), camel);
 /// assert_eq!(to_camel_case("HELLO_WORLD"), camel);
+/// assert_eq!(to_camel_case("ΟΔΟΣ_ΣΟΦΙΑΣ"), "οδοςΣοφιας".to_owned());
+/// assert_eq!(to_camel_case("velika ǆungla"), "velikaǅungla".to_owned());
+/// assert_eq!(to_camel_case("CAFE\u{301}_BAR"), "cafe\u{301}Bar".to_owned());
 /// ```
 pub fn to_camel_case(string: &str) -> String {
-  string
-  .chars()
+  with_next(string)
   .scan((false, None), scan_to_camel)
   .collect()
 }
@@ -244,10 +247,10 @@ pub fn is_camel_case(string: &str) -> bool {
 /// assert_eq!(to_pascal_case("Hello-World"), pascal);
 /// assert_eq!(to_pascal_case("hello_world"), pascal);
 /// assert_eq!(to_pascal_case("HELLO_WORLD"), pascal);
+/// assert_eq!(to_pascal_case("ǉubav_ǌegova"), "ǈubavǋegova".to_owned());
 /// ```
 pub fn to_pascal_case(string: &str) -> String {
-  string
-  .chars()
+  with_next(string)
   .scan((true, None), scan_to_camel)
   .collect()
 }
@@ -287,12 +290,7 @@ pub fn is_pascal_case(string: &str) -> bool {
 /// assert_eq!(to_kebab_case("HELLO_WORLD"), kebab);
 /// ```
 pub fn to_kebab_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, '-'))
-  .break_camel('-')
-  .flat_map(char::to_lowercase)
-  .collect()
+  separate(string, '-').to_lowercase()
 }
 
 /// Check to see if a string is kebab-case.
@@ -328,15 +326,11 @@ pub fn is_kebab_case(string: &str) -> bool {
 /// assert_eq!(to_train_case("Hello-World"), train);
 /// assert_eq!(to_train_case("hello_world"), train);
 /// assert_eq!(to_train_case("HELLO_WORLD"), train);
+/// assert_eq!(to_train_case("ǅUNGLA"), "ǅungla".to_owned());
+/// assert_eq!(to_train_case("e\u{301}cole_nationale"), "E\u{301}cole-Nationale".to_owned());
 /// ```
 pub fn to_train_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, '-'))
-  .break_camel('-')
-  .flat_map(char::to_lowercase)
-  .capitalize_words()
-  .collect()
+  capitalize_words(&separate(string, '-').to_lowercase())
 }
 
 /// Check to see if a string is Train-Case.
@@ -372,14 +366,11 @@ pub fn is_train_case(string: &str) -> bool {
 /// assert_eq!(to_snake_case("Hello-World"), snake);
 /// assert_eq!(to_snake_case("hello_world"), snake);
 /// assert_eq!(to_snake_case("HELLO_WORLD"), snake);
+/// assert_eq!(to_snake_case("ΟΔΟΣ ΣΟΦΙΑΣ"), "οδος_σοφιας".to_owned());
+/// assert_eq!(to_snake_case("cafe\u{301}Bar"), "cafe\u{301}_bar".to_owned());
 /// ```
 pub fn to_snake_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, '_'))
-  .break_camel('_')
-  .flat_map(char::to_lowercase)
-  .collect()
+  separate(string, '_').to_lowercase()
 }
 
 /// Check to see if a string is snake_case.
@@ -417,12 +408,7 @@ pub fn is_snake_case(string: &str) -> bool {
 /// assert_eq!(to_constant_case("HELLO_WORLD"), constant);
 /// ```
 pub fn to_constant_case(string: &str) -> String {
-  string
-  .chars()
-  .map(|c| swap_separator(c, '_'))
-  .break_camel('_')
-  .flat_map(char::to_uppercase)
-  .collect()
+  separate(string, '_').to_uppercase()
 }
 
 /// Check to see if a string is CONSTANT_CASE.
@@ -443,9 +429,595 @@ pub fn is_constant_case(string: &str) -> bool {
   string == to_constant_case(string)
 }
 
+/// Converts an identifier into a human readable label, removing a trailing
+/// `_id` and capitalizing only the first word. Uses the rules of the default
+/// `Inflector`, which may be configured with human rules and acronyms.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::humanize;
+/// assert_eq!(humanize("employee_salary"), "Employee salary".to_owned());
+/// assert_eq!(humanize("author_id"), "Author".to_owned());
+/// assert_eq!(humanize("authorId"), "Author".to_owned());
+/// assert_eq!(humanize("HelloWorld"), "Hello world".to_owned());
+/// assert_eq!(humanize("id"), "Id".to_owned());
+/// ```
+pub fn humanize(string: &str) -> String {
+  default_inflector().humanize(string)
+}
+
+/// Converts prose into sentence case, capitalizing the first word of every
+/// sentence and lower casing the other words. Unlike `to_sentence_case`,
+/// punctuation is kept and abbreviations like “e.g.” do not end a sentence.
+/// Uses the acronyms of the default `Inflector`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::to_prose_case;
+/// assert_eq!(to_prose_case("THANKS FOR SIGNING UP! we will be in touch."), "Thanks for signing up! We will be in touch.".to_owned());
+/// assert_eq!(to_prose_case("bring snacks, e.g. fruit. or not"), "Bring snacks, e.g. fruit. Or not".to_owned());
+/// ```
+pub fn to_prose_case(string: &str) -> String {
+  default_inflector().to_prose_case(string)
+}
+
+/// Words which keep their original form when converting a string into
+/// title case, Train-Case, or sentence case with `to_title_case_with`,
+/// `to_train_case_with`, and `to_sentence_case_with`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_title_case_with, Preserve};
+/// let preserve = Preserve::new().roman_numerals().acronyms(2);
+/// assert_eq!(to_title_case_with("world war II", &preserve), "World War II".to_owned());
+/// assert_eq!(to_title_case_with("NASA launch", &preserve), "NASA Launch".to_owned());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Preserve {
+  /// Whether upper case Roman numerals like “III” are kept.
+  roman_numerals: bool,
+  /// The minimum number of letters of upper case acronyms which are kept.
+  acronyms: Option<usize>,
+}
+
+impl Preserve {
+  /// Creates options which keep no words.
+  pub fn new() -> Self {
+    Preserve::default()
+  }
+
+  /// Keeps upper case Roman numerals like “II” or “XIV.”
+  pub fn roman_numerals(mut self) -> Self {
+    self.roman_numerals = true;
+    self
+  }
+
+  /// Keeps upper case acronyms like “NASA” with at least `min_len` letters.
+  pub fn acronyms(mut self, min_len: usize) -> Self {
+    self.acronyms = Some(min_len);
+    self
+  }
+
+  /// Checks if a word keeps its original form.
+  fn keeps(&self, word: &str) -> bool {
+    let upper = word.chars().any(char::is_uppercase) && !word.chars().any(char::is_lowercase);
+    upper && (
+      (self.roman_numerals && is_roman_numeral(word)) ||
+      self.acronyms.is_some_and(|min_len| word.chars().filter(|c| c.is_alphabetic()).count() >= min_len)
+    )
+  }
+}
+
+/// Converts any case into title case like `to_title_case`, except for the
+/// words kept by `preserve`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_title_case_with, Preserve};
+/// let preserve = Preserve::new().roman_numerals();
+/// assert_eq!(to_title_case_with("release_III", &preserve), "Release III".to_owned());
+/// assert_eq!(to_title_case_with("release_iii", &preserve), "Release Iii".to_owned());
+/// assert_eq!(to_title_case_with("chapter XIV", &preserve), "Chapter XIV".to_owned());
+/// assert_eq!(to_title_case_with("chapter IIII", &preserve), "Chapter Iiii".to_owned());
+/// assert_eq!(to_title_case_with("NASA launch", &preserve), "Nasa Launch".to_owned());
+/// ```
+pub fn to_title_case_with(string: &str, preserve: &Preserve) -> String {
+  let words = separate_keeping(string, ' ', is_proper_noun);
+  convert_preserving(&words, ' ', preserve, |word| capitalize_title_word(&word.to_lowercase()))
+}
+
+/// Converts any case into Train-Case like `to_train_case`, except for the
+/// words kept by `preserve`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_train_case_with, Preserve};
+/// let preserve = Preserve::new().acronyms(3);
+/// assert_eq!(to_train_case_with("HTTP request ID", &preserve), "HTTP-Request-Id".to_owned());
+/// ```
+pub fn to_train_case_with(string: &str, preserve: &Preserve) -> String {
+  convert_preserving(&separate(string, '-'), '-', preserve, |word| capitalize(&word.to_lowercase()))
+}
+
+/// Converts any case into sentence case like `to_sentence_case`, except for
+/// the words kept by `preserve`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_sentence_case_with, Preserve};
+/// let preserve = Preserve::new().roman_numerals().acronyms(2);
+/// assert_eq!(to_sentence_case_with("Henry_VIII_And_The_BBC", &preserve), "henry VIII and the BBC".to_owned());
+/// ```
+pub fn to_sentence_case_with(string: &str, preserve: &Preserve) -> String {
+  convert_preserving(&separate(string, ' '), ' ', preserve, str::to_lowercase)
+}
+
+/// Splits a string into words with `sep` and converts every word which is
+/// not kept by `preserve`.
+fn convert_preserving<F: Fn(&str) -> String>(words: &str, sep: char, preserve: &Preserve, convert: F) -> String {
+  let mut converted = String::with_capacity(words.len());
+  for (i, word) in words.split(sep).enumerate() {
+    if i > 0 {
+      converted.push(sep);
+    }
+    if preserve.keeps(word) {
+      converted.push_str(word);
+    } else {
+      converted.push_str(&convert(word));
+    }
+  }
+  converted
+}
+
+/// Checks if an upper case word is a Roman numeral in its canonical form,
+/// from “I” to “MMMCMXCIX.”
+fn is_roman_numeral(word: &str) -> bool {
+  const NUMERALS: [(usize, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"),
+    (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+  ];
+  let mut rest = word;
+  let mut value = 0;
+  for &(numeral_value, numeral) in &NUMERALS {
+    while let Some(stripped) = rest.strip_prefix(numeral) {
+      rest = stripped;
+      value += numeral_value;
+    }
+  }
+  if !rest.is_empty() || value == 0 || value >= 4000 {
+    return false;
+  }
+
+  // Write the value canonically, so words like “IIII” or “IXI” are rejected.
+  let mut canonical = String::with_capacity(word.len());
+  for &(numeral_value, numeral) in &NUMERALS {
+    while value >= numeral_value {
+      canonical.push_str(numeral);
+      value -= numeral_value;
+    }
+  }
+  canonical == word
+}
+
+/// A language whose case mappings are tailored in Unicode's
+/// `SpecialCasing.txt`. Every other language uses the root mappings.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::Locale;
+/// assert_eq!(Locale::from_tag("tr-TR"), Locale::Turkish);
+/// assert_eq!(Locale::from_tag("en"), Locale::Root);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+  /// The case mappings of the Unicode character database.
+  #[default]
+  Root,
+  /// Turkish, where the dotted “i” and dotless “ı” are written “İ” and “I”
+  /// in upper case.
+  Turkish,
+  /// Azerbaijani, which cases the dotted and dotless “i” like Turkish.
+  Azerbaijani,
+  /// Lithuanian, which keeps the dot of a lower case “i” under an accent.
+  Lithuanian,
+}
+
+impl Locale {
+  /// Gets the locale of a language tag like `tr` or `lt-LT`. Languages
+  /// without tailored case mappings use `Locale::Root`.
+  pub fn from_tag(tag: &str) -> Self {
+    match language(tag).as_str() {
+      "tr" => Locale::Turkish,
+      "az" => Locale::Azerbaijani,
+      "lt" => Locale::Lithuanian,
+      _ => Locale::Root,
+    }
+  }
+
+  /// Converts a string into lower case.
+  fn to_lowercase(self, string: &str) -> String {
+    let mut tailored = String::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+      let next = chars.peek().cloned();
+      match (self, c) {
+        (Locale::Turkish, 'İ') | (Locale::Azerbaijani, 'İ') => tailored.push('i'),
+        (Locale::Turkish, 'I') | (Locale::Azerbaijani, 'I') if next == Some('\u{307}') => {
+          chars.next();
+          tailored.push('i');
+        },
+        (Locale::Turkish, 'I') | (Locale::Azerbaijani, 'I') => tailored.push('ı'),
+        (Locale::Lithuanian, 'Ì') => tailored.push_str("i\u{307}\u{300}"),
+        (Locale::Lithuanian, 'Í') => tailored.push_str("i\u{307}\u{301}"),
+        (Locale::Lithuanian, 'Ĩ') => tailored.push_str("i\u{307}\u{303}"),
+        (Locale::Lithuanian, 'I') | (Locale::Lithuanian, 'J') | (Locale::Lithuanian, 'Į')
+          if next.is_some_and(is_mark_above) => {
+          tailored.extend(c.to_lowercase());
+          tailored.push('\u{307}');
+        },
+        _ => tailored.push(c),
+      }
+    }
+    tailored.to_lowercase()
+  }
+
+  /// Converts a string into upper case.
+  fn to_uppercase(self, string: &str) -> String {
+    let mut tailored = String::with_capacity(string.len());
+    let mut last = None;
+    for c in string.chars() {
+      match (self, c) {
+        (Locale::Turkish, 'i') | (Locale::Azerbaijani, 'i') => tailored.push('İ'),
+        // The dot above a soft dotted letter is dropped with the letter's
+        // own dot.
+        (Locale::Lithuanian, '\u{307}') if last.is_some_and(|c| ['i', 'j', 'į', 'ɨ'].contains(&c)) => {},
+        _ => tailored.push(c),
+      }
+      last = Some(c);
+    }
+    tailored.to_uppercase()
+  }
+
+  /// Capitalizes the first letter of a word, and lower cases the rest.
+  fn capitalize(self, word: &str) -> String {
+    let lower = self.to_lowercase(word);
+    // Marks after the first letter are upper cased with it.
+    let first_len = lower.graphemes(true).next().map_or(0, str::len);
+    let (first, rest) = lower.split_at(first_len);
+    let mut chars = first.chars();
+    // Letters like the digraph “ǆ” have a titlecase mapping of their own.
+    let title = match chars.next().map(to_titlecase) {
+      Some(ToTitlecase::Mapped(title)) => title.as_str().to_owned() + &self.to_uppercase(chars.as_str()),
+      _ => self.to_uppercase(first),
+    };
+    title + rest
+  }
+}
+
+/// Checks if a character is a combining mark written above a letter.
+#[inline]
+fn is_mark_above(c: char) -> bool {
+  matches!(
+    c,
+    '\u{300}'..='\u{314}' | '\u{33d}'..='\u{344}' | '\u{346}' | '\u{34a}'..='\u{34c}' |
+    '\u{350}'..='\u{352}' | '\u{357}' | '\u{35b}' | '\u{363}'..='\u{36f}'
+  )
+}
+
+/// Converts between cases using the case mappings of a `Locale`, optionally
+/// normalizing strings into a Unicode normalization form before and after
+/// every conversion. The locale-free functions in this module use the root
+/// mappings and do not normalize.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{CaseConverter, Locale};
+/// let turkish = CaseConverter::new(Locale::Turkish);
+/// assert_eq!(turkish.to_title_case("istanbul_ve_izmir"), "İstanbul Ve İzmir".to_owned());
+/// assert_eq!(turkish.to_constant_case("yeni şehir"), "YENİ_ŞEHİR".to_owned());
+/// assert_eq!(turkish.to_snake_case("DİYARBAKIR"), "diyarbakır".to_owned());
+///
+/// let root = CaseConverter::new(Locale::Root);
+/// assert_eq!(root.to_pascal_case("ǆungla ǉubav"), "ǅunglaǈubav".to_owned());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CaseConverter {
+  locale: Locale,
+  /// The form strings are normalized into, if any.
+  normalization: Option<Normalization>,
+}
+
+impl CaseConverter {
+  /// Creates a converter using the case mappings of `locale`.
+  pub fn new(locale: Locale) -> Self {
+    CaseConverter {
+      locale,
+      normalization: None,
+    }
+  }
+
+  /// Normalizes strings into `form` before and after every conversion, and
+  /// before comparing them in the `is_*` methods.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale, Normalization};
+  /// let nfc = CaseConverter::new(Locale::Root).normalization(Normalization::Nfc);
+  /// assert_eq!(nfc.to_snake_case("Cafe\u{301}Bar"), "caf\u{e9}_bar".to_owned());
+  /// assert!(nfc.is_snake_case("cafe\u{301}_bar"));
+  ///
+  /// let nfkc = CaseConverter::new(Locale::Root).normalization(Normalization::Nfkc);
+  /// assert_eq!(nfkc.to_snake_case("ＡＢＣ　ｄｅｆ"), "abc_def".to_owned());
+  /// ```
+  pub fn normalization(mut self, form: Normalization) -> Self {
+    self.normalization = Some(form);
+    self
+  }
+
+  /// Converts any case into lower case ignoring separators.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale};
+  /// assert_eq!(CaseConverter::new(Locale::Azerbaijani).to_lower_case("İLKIN"), "ilkın".to_owned());
+  /// assert_eq!(CaseConverter::new(Locale::Lithuanian).to_lower_case("ÌS"), "i\u{307}\u{300}s".to_owned());
+  /// ```
+  pub fn to_lower_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_lowercase(string))
+  }
+
+  /// Check to see if a string is completely lower case.
+  pub fn is_lower_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_lower_case(string)
+  }
+
+  /// Converts any case into UPPER CASE ignoring separators.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale};
+  /// assert_eq!(CaseConverter::new(Locale::Turkish).to_upper_case("ilkın"), "İLKIN".to_owned());
+  /// assert_eq!(CaseConverter::new(Locale::Lithuanian).to_upper_case("i\u{307}\u{300}s"), "I\u{300}S".to_owned());
+  /// ```
+  pub fn to_upper_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_uppercase(string))
+  }
+
+  /// Check to see if a string is completely UPPER CASE.
+  pub fn is_upper_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_upper_case(string)
+  }
+
+  /// Converts any case into traditional sentence case without capitalizing
+  /// the first letter.
+  pub fn to_sentence_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_lowercase(&separate(string, ' ')))
+  }
+
+  /// Check to see if a string is sentence case.
+  pub fn is_sentence_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_sentence_case(string)
+  }
+
+  /// Converts any case into Title Case, writing proper nouns like “iPhone” in
+  /// their spelling like `to_title_case`.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale};
+  /// assert_eq!(CaseConverter::new(Locale::Root).to_title_case("iphone_sync"), "iPhone Sync".to_owned());
+  /// ```
+  pub fn to_title_case(&self, string: &str) -> String {
+    self.convert(string, |string| {
+      separate_keeping(string, ' ', |word| proper_noun(&self.locale.to_lowercase(word)).is_some())
+      .split(' ')
+      .map(|word| {
+        let lower = self.locale.to_lowercase(word);
+        proper_noun(&lower).map_or_else(|| self.locale.capitalize(word), str::to_owned)
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+    })
+  }
+
+  /// Check to see if a string is Title Case.
+  pub fn is_title_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_title_case(string)
+  }
+
+  /// Converts any case into camelCase.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale};
+  /// assert_eq!(CaseConverter::new(Locale::Turkish).to_camel_case("IŞIK_İÇİN"), "ışıkİçin".to_owned());
+  /// ```
+  pub fn to_camel_case(&self, string: &str) -> String {
+    self.convert(string, |string| {
+      separate(string, ' ')
+      .split(' ')
+      .filter(|word| !word.is_empty())
+      .enumerate()
+      .map(|(i, word)| if i == 0 { self.locale.to_lowercase(word) } else { self.locale.capitalize(word) })
+      .collect()
+    })
+  }
+
+  /// Check to see if a string is camelCase.
+  pub fn is_camel_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_camel_case(string)
+  }
+
+  /// Converts any case into PascalCase.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::{CaseConverter, Locale};
+  /// assert_eq!(CaseConverter::new(Locale::Turkish).to_pascal_case("ilk iş"), "İlkİş".to_owned());
+  /// ```
+  pub fn to_pascal_case(&self, string: &str) -> String {
+    self.convert(string, |string| {
+      separate(string, ' ')
+      .split(' ')
+      .map(|word| self.locale.capitalize(word))
+      .collect()
+    })
+  }
+
+  /// Check to see if a string is PascalCase.
+  pub fn is_pascal_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_pascal_case(string)
+  }
+
+  /// Converts any case into kebab-case.
+  pub fn to_kebab_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_lowercase(&separate(string, '-')))
+  }
+
+  /// Check to see if a string is kebab-case.
+  pub fn is_kebab_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_kebab_case(string)
+  }
+
+  /// Converts any case into Train-Case.
+  pub fn to_train_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.capitalize_words(string, '-'))
+  }
+
+  /// Check to see if a string is Train-Case.
+  pub fn is_train_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_train_case(string)
+  }
+
+  /// Converts any case into snake_case.
+  pub fn to_snake_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_lowercase(&separate(string, '_')))
+  }
+
+  /// Check to see if a string is snake_case.
+  pub fn is_snake_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_snake_case(string)
+  }
+
+  /// Converts any case into CONSTANT_CASE.
+  pub fn to_constant_case(&self, string: &str) -> String {
+    self.convert(string, |string| self.locale.to_uppercase(&separate(string, '_')))
+  }
+
+  /// Check to see if a string is CONSTANT_CASE.
+  pub fn is_constant_case(&self, string: &str) -> bool {
+    self.normalize(string) == self.to_constant_case(string)
+  }
+
+  /// Separates words with `sep` and capitalizes every word.
+  fn capitalize_words(&self, string: &str, sep: char) -> String {
+    separate(string, sep)
+    .split(sep)
+    .map(|word| self.locale.capitalize(word))
+    .collect::<Vec<_>>()
+    .join(&sep.to_string())
+  }
+
+  /// Normalizes a string, converts it, and normalizes the result.
+  fn convert<F: FnOnce(&str) -> String>(&self, string: &str, convert: F) -> String {
+    match self.normalization {
+      Some(form) => form.normalize(&convert(&form.normalize(string))),
+      None => convert(string),
+    }
+  }
+
+  /// Normalizes a string if the converter has a normalization form.
+  fn normalize(&self, string: &str) -> String {
+    match self.normalization {
+      Some(form) => form.normalize(string),
+      None => string.to_owned(),
+    }
+  }
+}
+
+/// A Unicode normalization form, which decides whether characters like “é”
+/// are written as one character or as a letter and a combining mark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Normalization {
+  /// Canonical composition, which writes “é” as one character. Most text,
+  /// and most databases, use this form.
+  Nfc,
+  /// Canonical decomposition, which writes “é” as “e” and a combining acute
+  /// accent, like the file names of macOS.
+  Nfd,
+  /// Compatibility composition, which also folds variants like the full
+  /// width “Ａ” into “A.” Useful when generating identifiers.
+  Nfkc,
+  /// Compatibility decomposition.
+  Nfkd,
+}
+
+impl Normalization {
+  /// Normalizes a string into this form.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::case::Normalization;
+  /// assert_eq!(Normalization::Nfc.normalize("e\u{301}"), "\u{e9}".to_owned());
+  /// assert_eq!(Normalization::Nfd.normalize("\u{e9}"), "e\u{301}".to_owned());
+  /// assert_eq!(Normalization::Nfkc.normalize("ＡＢＣ"), "ABC".to_owned());
+  /// assert_eq!(Normalization::Nfkd.normalize("ﬁ"), "fi".to_owned());
+  /// ```
+  pub fn normalize(self, string: &str) -> String {
+    match self {
+      Normalization::Nfc => string.nfc().collect(),
+      Normalization::Nfd => string.nfd().collect(),
+      Normalization::Nfkc => string.nfkc().collect(),
+      Normalization::Nfkd => string.nfkd().collect(),
+    }
+  }
+}
+
+/// Swaps separators for `sep` and separates the words of camelCase strings,
+/// breaking between a lower case and an upper case grapheme cluster. A
+/// cluster is cased like its first character, so a break never lands between
+/// a letter and its combining marks.
+fn separate(string: &str, sep: char) -> String {
+  let mut separated = String::with_capacity(string.len());
+  let mut clusters = string.graphemes(true).peekable();
+  while let Some(cluster) = clusters.next() {
+    let mut chars = cluster.chars();
+    separated.extend(chars.next().map(|c| swap_separator(c, sep)));
+    separated.push_str(chars.as_str());
+    if base(cluster).is_lowercase() && clusters.peek().is_some_and(|next| base(next).is_uppercase()) {
+      separated.push(sep);
+    }
+  }
+  separated
+}
+
+/// Separates words like `separate`, except for the words kept whole by
+/// `is_kept`, like proper nouns such as “iPhone” which have capital letters
+/// inside them.
+pub(crate) fn separate_keeping<K: Fn(&str) -> bool>(string: &str, sep: char, is_kept: K) -> String {
+  let mut separated = String::with_capacity(string.len());
+  for (i, word) in string.split(is_separator).enumerate() {
+    if i > 0 {
+      separated.push(sep);
+    }
+    if is_kept(word) {
+      separated.push_str(word);
+    } else {
+      separated.push_str(&separate(word, sep));
+    }
+  }
+  separated
+}
+
+/// The first character of a grapheme cluster, which decides its case.
+#[inline]
+fn base(cluster: &str) -> char {
+  cluster.chars().next().unwrap_or_default()
+}
+
 /// Checks if a character is a separator.
 #[inline]
-fn is_separator(c: char) -> bool {
+pub(crate) fn is_separator(c: char) -> bool {
   c == ' ' || c == '-' || c == '_'
 }
 
@@ -461,162 +1033,88 @@ fn swap_separator(c: char, sep: char) -> char {
   }
 }
 
-/// The function to be used with the iterator `scan` method which converts a
-/// char iterator into a string iterator which has
+/// The function to be used with the iterator `scan` method which converts an
+/// iterator of grapheme clusters into a string iterator which has
 /// removed/uppercased/lowercased the bits which need for the conversion to be
 /// successful. This would work best with a `flat_scan`.
 #[inline]
-fn scan_to_camel(state: &mut (bool, Option<char>), curr: char) -> Option<String> {
-  // Store the last character in the scope and update the state to use the
-  // current character.
+fn scan_to_camel(state: &mut (bool, Option<char>), (curr, next): (&str, Option<&str>)) -> Option<String> {
+  // Store the first character of the last cluster in the scope and update
+  // the state to use the current cluster.
   let last = state.1;
-  state.1 = Some(curr);
+  let first = base(curr);
+  state.1 = Some(first);
+  let marks = &curr[first.len_utf8()..];
 
   if state.0 {
-    // If the state has signaled the next character must be capitalized,
+    // If the state has signaled the next cluster must be capitalized,
     // capitalize it and mark the state as finished.
     state.0 = false;
-    Some(curr.to_uppercase().collect())
-  } else if is_separator(curr) {
-    // If the current character is a separator, mark the state to capitalize
-    // the next character and remove the separator.
+    Some(to_titlecase(first).chain(marks.chars()).collect())
+  } else if is_separator(first) {
+    // If the current cluster is a separator, mark the state to capitalize
+    // the next cluster and remove the separator.
     state.0 = true;
-    Some("".to_owned())
-  } else if !last.map_or(false, char::is_lowercase) {
-    // If the last character was not lowercase, this character should be
-    // lower cased. This magic preserves camelCase strings while lowercasing
-    // cases like CONSTANT_CASE.
-    Some(curr.to_lowercase().collect())
+    Some(marks.to_owned())
+  } else if !last.is_some_and(char::is_lowercase) {
+    // If the last cluster was not lowercase, this cluster should be lower
+    // cased. This magic preserves camelCase strings while lowercasing cases
+    // like CONSTANT_CASE.
+    Some(lower_case_in_word(first, last, next.map(base)) + &marks.to_lowercase())
   } else {
-    // Otherwise, just return the character.
-    let mut string = String::with_capacity(1);
-    string.push(curr);
-    Some(string)
-  }
-}
-
-/// Trait with some extra methods for the iterators we use.
-trait Extras: Iterator<Item=char> {
-  /// Uses the `BreakCamel` type to break apart camel case strings, i.e.
-  /// strings like `helloWorld` to `hello world` using the `sep` argument to
-  /// seperate the new words.
-  #[inline]
-  fn break_camel(self, sep: char) -> BreakCamel<Self> where Self: Sized {
-    BreakCamel {
-      iter: self.peekable(),
-      sep: sep,
-      br: false
-    }
-  }
-
-  /// Uses the `CapitalizeWords` type to capitilize individual words seperated
-  /// by a separator (as defined by `is_separator`).
-  #[inline]
-  fn capitalize_words(self) -> CapitalizeWords<Self> where Self: Sized {
-    let mut iter = self.peekable();
-    // If the first character is not a separator, we want to capitilize it.
-    let cap = !iter.peek().cloned().map_or(false, is_separator);
-    CapitalizeWords {
-      iter: iter,
-      cap: cap,
-      upper: None
-    }
+    // Otherwise, just return the cluster.
+    Some(curr.to_owned())
   }
 }
 
-/// Add the extra methods to the iterators.
-impl<I> Extras for I where I: Iterator<Item=char> {}
-
-/// Utility for breaking apart camelCased strings.
-struct BreakCamel<I> where I: Iterator<Item=char> {
-  /// The source iterator.
-  iter: Peekable<I>,
-  /// Character to use when breaking apart camelCase strings.
-  sep: char,
-  /// Iterator state representing whether the iterator should insert a break.
-  br: bool
+/// Pairs every grapheme cluster of a string with the cluster after it.
+#[inline]
+fn with_next(string: &str) -> impl Iterator<Item=(&str, Option<&str>)> {
+  string.graphemes(true).zip(string.graphemes(true).skip(1).map(Some).chain(iter::once(None)))
 }
 
-impl<I> Iterator for BreakCamel<I> where I: Iterator<Item=char> {
-  type Item = char;
-
-  #[inline]
-  fn next(&mut self) -> Option<Self::Item> {
-    // If we have been signaled to break, the next item is a separator and we
-    // should disable break mode.
-    if self.br {
-      self.br = false;
-      return Some(self.sep);
-    }
-
-    match (self.iter.next(), self.iter.peek()) {
-      // If we have a current character that is lowercase and we have a next
-      // character that is uppercase, we need to break the string apart next
-      // time `next` is called.
-      (Some(curr), Some(next)) if curr.is_lowercase() && next.is_uppercase() => {
-        self.br = true;
-        Some(curr)
-      },
-      // Otherwise behave as normal.
-      (Some(curr), _) => Some(curr),
-      (None, _) => None
-    }
+/// Lower cases a character between two others. A capital sigma at the end
+/// of a word is written as the final sigma “ς,” like in `str::to_lowercase`.
+#[inline]
+fn lower_case_in_word(curr: char, last: Option<char>, next: Option<char>) -> String {
+  let is_cased = |c: char| c.is_lowercase() || c.is_uppercase();
+  if curr == 'Σ' && last.is_some_and(is_cased) && !next.is_some_and(is_cased) {
+    "ς".to_owned()
+  } else {
+    curr.to_lowercase().collect()
   }
 }
 
-/// Utility for capitalizing words.
-struct CapitalizeWords<I> where I: Iterator<Item=char> {
-  /// The source iterator.
-  iter: Peekable<I>,
-  /// Iteration state indicating whether or not the next letter should be
-  /// capitalized.
-  cap: bool,
-  /// An iterator for the letter being upper cased.
-  upper: Option<ToUppercase>
+/// Checks if a word in any case is one of the proper nouns of the English
+/// rules.
+#[inline]
+fn is_proper_noun(word: &str) -> bool {
+  proper_noun(&word.to_lowercase()).is_some()
 }
 
-impl<I> Iterator for CapitalizeWords<I> where I: Iterator<Item=char> {
-  type Item = char;
-
-  #[inline]
-  fn next(&mut self) -> Option<Self::Item> {
-    // Note: only one control path should allow this loop to continue.
-    loop {
-      // If there is an upper casing still in progress…
-      if let Some(ref mut upper) = self.upper {
-        if let Some(next) = upper.next() {
-          // If there was a character in the uppercase iterator, return it.
-          return Some(next);
-        }
-      }
-
-      // If we are here this means we have exhausted the upper case iterator,
-      // therefore we reset the upper iterator state.
-      if self.upper.is_some() {
-        self.upper = None;
-      }
+/// Capitalizes a lower case word for title case, or writes it in its
+/// spelling if it is a proper noun like “iPhone.”
+fn capitalize_title_word(word: &str) -> String {
+  proper_noun(word).map_or_else(|| capitalize_words(word), str::to_owned)
+}
 
-      if let Some(c) = self.iter.next() {
-        // If there is a character…
-        if self.cap {
-          // If we have been signaled to capitalize the next character, disable
-          // the signal, and enable the capitalization iterator.
-          self.cap = false;
-          self.upper = Some(c.to_uppercase());
-          // We want it to loop back here…
-        } else {
-          // Otherwise return the character, but if it is a separator, and the
-          // next character is not a separator—signal the next character should
-          // be capitalized.
-          if is_separator(c) && !self.iter.peek().cloned().map_or(false, is_separator) {
-            self.cap = true;
-          }
-          return Some(c);
-        }
-      } else {
-        // End the iterator.
-        return None;
-      }
+/// Capitalizes the first grapheme cluster of every word separated by a
+/// separator (as defined by `is_separator`).
+fn capitalize_words(string: &str) -> String {
+  let mut capitalized = String::with_capacity(string.len());
+  let mut cap = true;
+  for cluster in string.graphemes(true) {
+    let first = base(cluster);
+    if is_separator(first) {
+      cap = true;
+      capitalized.push_str(cluster);
+    } else if cap {
+      cap = false;
+      capitalized.extend(to_titlecase(first));
+      capitalized.push_str(&cluster[first.len_utf8()..]);
+    } else {
+      capitalized.push_str(cluster);
     }
   }
+  capitalized
 }
@@ -0,0 +1,127 @@
+//! The Dutch inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "en"),
+  ("(el|er|em|en|je|e)$", "${1}s"),
+  ("([aiouy])$", "${1}'s"),
+  ("aa([^aeiou])$", "a${1}en"),
+  ("ee([^aeiou])$", "e${1}en"),
+  ("oo([^aeiou])$", "o${1}en"),
+  ("uu([^aeiou])$", "u${1}en"),
+  ("^([^aeiou]*[aeiou])([bdfgklmnprst])$", "${1}${2}${2}en"),
+  ("aas$", "azen"),
+  ("oos$", "ozen"),
+  ("ees$", "ezen"),
+  ("uis$", "uizen"),
+  ("ief$", "ieven"),
+  ("heid$", "heden"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("en$", ""),
+  ("s$", ""),
+  ("'s$", ""),
+  ("([^aeiou])a([^aeiou])en$", "${1}aa${2}"),
+  ("([^aeiou])e([^aeiou])en$", "${1}ee${2}"),
+  ("([^aeiou])o([^aeiou])en$", "${1}oo${2}"),
+  ("([^aeiou])u([^aeiou])en$", "${1}uu${2}"),
+  (
+    "^([^aeiou]*[aeiou])(?:(b)b|(d)d|(f)f|(g)g|(k)k|(l)l|(m)m|(n)n|(p)p|(r)r|(s)s|(t)t)en$",
+    "${1}${2}${3}${4}${5}${6}${7}${8}${9}${10}${11}${12}${13}",
+  ),
+  ("azen$", "aas"),
+  ("ozen$", "oos"),
+  ("ezen$", "ees"),
+  ("uizen$", "uis"),
+  ("ieven$", "ief"),
+  ("heden$", "heid"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("kind", "kinderen"),
+  ("ei", "eieren"),
+  ("lam", "lammeren"),
+  ("blad", "bladeren"),
+  ("stad", "steden"),
+  ("schip", "schepen"),
+  ("lid", "leden"),
+  ("dag", "dagen"),
+  ("weg", "wegen"),
+  ("dak", "daken"),
+  ("glas", "glazen"),
+  ("gat", "gaten"),
+  ("pad", "paden"),
+  ("slot", "sloten"),
+  ("god", "goden"),
+  ("koe", "koeien"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "informatie",
+  "vee",
+];
+
+/// Builds an inflector with the Dutch rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("boek", "boeken"),
+    ("land", "landen"),
+    ("tafel", "tafels"),
+    ("kamer", "kamers"),
+    ("jongen", "jongens"),
+    ("meisje", "meisjes"),
+    ("tante", "tantes"),
+    ("auto", "auto's"),
+    ("foto", "foto's"),
+    ("baby", "baby's"),
+    ("boom", "bomen"),
+    ("maan", "manen"),
+    ("been", "benen"),
+    ("vuur", "vuren"),
+    ("bal", "ballen"),
+    ("kat", "katten"),
+    ("bed", "bedden"),
+    ("man", "mannen"),
+    ("bus", "bussen"),
+    ("huis", "huizen"),
+    ("kaas", "kazen"),
+    ("roos", "rozen"),
+    ("brief", "brieven"),
+    ("waarheid", "waarheden"),
+    ("kind", "kinderen"),
+    ("stad", "steden"),
+    ("dag", "dagen"),
+    ("Huis", "Huizen"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
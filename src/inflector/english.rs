@@ -0,0 +1,149 @@
+//! The English inflection rules used by the default `Inflector`.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "s"),
+  ("s$", "s"),
+  ("^(ax|test)is$", "${1}es"),
+  ("(octop|vir)us$", "${1}i"),
+  ("(octop|vir)i$", "${1}i"),
+  ("(alias|status)$", "${1}es"),
+  ("(bu)s$", "${1}ses"),
+  ("(buffal|tomat)o$", "${1}oes"),
+  ("([ti])um$", "${1}a"),
+  ("([ti])a$", "${1}a"),
+  ("sis$", "ses"),
+  ("(?:([^f])fe|([lr])f)$", "${1}${2}ves"),
+  ("(hive)$", "${1}s"),
+  ("([^aeiouy]|qu)y$", "${1}ies"),
+  ("(x|ch|ss|sh)$", "${1}es"),
+  ("(matr|vert|ind)(?:ix|ex)$", "${1}ices"),
+  ("^(m|l)ouse$", "${1}ice"),
+  ("^(m|l)ice$", "${1}ice"),
+  ("^(ox)$", "${1}en"),
+  ("^(oxen)$", "${1}"),
+  ("(quiz)$", "${1}zes"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("s$", ""),
+  ("(ss)$", "${1}"),
+  ("(n)ews$", "${1}ews"),
+  ("([ti])a$", "${1}um"),
+  ("((a)naly|(b)a|(d)iagno|(p)arenthe|(p)rogno|(s)ynop|(t)he)(sis|ses)$", "${1}sis"),
+  ("(^analy)(sis|ses)$", "${1}sis"),
+  ("([^f])ves$", "${1}fe"),
+  ("(hive)s$", "${1}"),
+  ("(tive)s$", "${1}"),
+  ("([lr])ves$", "${1}f"),
+  ("([^aeiouy]|qu)ies$", "${1}y"),
+  ("(s)eries$", "${1}eries"),
+  ("(m)ovies$", "${1}ovie"),
+  ("(x|ch|ss|sh)es$", "${1}"),
+  ("^(m|l)ice$", "${1}ouse"),
+  ("(bus)(es)?$", "${1}"),
+  ("(o)es$", "${1}"),
+  ("(shoe)s$", "${1}"),
+  ("(cris|test)(is|es)$", "${1}is"),
+  ("^(a)x[ie]s$", "${1}xis"),
+  ("(octop|vir)(us|i)$", "${1}us"),
+  ("(alias|status)(es)?$", "${1}"),
+  ("^(ox)en$", "${1}"),
+  ("(vert|ind)ices$", "${1}ex"),
+  ("(matr)ices$", "${1}ix"),
+  ("(quiz)zes$", "${1}"),
+  ("(database)s$", "${1}"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("person", "people"),
+  ("man", "men"),
+  ("woman", "women"),
+  ("child", "children"),
+  ("sex", "sexes"),
+  ("move", "moves"),
+  ("zombie", "zombies"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "equipment",
+  "information",
+  "rice",
+  "money",
+  "species",
+  "series",
+  "fish",
+  "sheep",
+  "jeans",
+  "police",
+];
+
+/// Compound nouns which do not inflect their last word and are not covered by
+/// the preposition rule, as `(singular, plural)` pairs.
+const COMPOUNDS: &[(&str, &str)] = &[
+  ("attorney general", "attorneys general"),
+  ("governor general", "governors general"),
+  ("postmaster general", "postmasters general"),
+  ("secretary general", "secretaries general"),
+  ("surgeon general", "surgeons general"),
+  ("court martial", "courts martial"),
+  ("notary public", "notaries public"),
+  ("poet laureate", "poets laureate"),
+  ("heir apparent", "heirs apparent"),
+  ("passerby", "passersby"),
+  ("passer by", "passers by"),
+  ("runner up", "runners up"),
+  ("hanger on", "hangers on"),
+  ("looker on", "lookers on"),
+];
+
+/// Prepositions which join a compound noun’s head word to its complement, as
+/// in “mother-in-law” or “man-of-war.”
+const PREPOSITIONS: &[&str] = &["of", "in", "on", "at"];
+
+/// Proper nouns, mostly brand names, whose spelling is not title case, keyed
+/// by their lower case spelling and sorted by key.
+const PROPER_NOUNS: &[(&str, &str)] = &[
+  ("bigquery", "BigQuery"), ("coffeescript", "CoffeeScript"), ("devops", "DevOps"),
+  ("dynamodb", "DynamoDB"), ("ebay", "eBay"), ("freebsd", "FreeBSD"), ("github", "GitHub"),
+  ("gitlab", "GitLab"), ("graphql", "GraphQL"), ("icloud", "iCloud"), ("imac", "iMac"),
+  ("ios", "iOS"), ("ipad", "iPad"), ("ipados", "iPadOS"), ("iphone", "iPhone"), ("ipod", "iPod"),
+  ("itunes", "iTunes"), ("javascript", "JavaScript"), ("latex", "LaTeX"), ("linkedin", "LinkedIn"),
+  ("macos", "macOS"), ("mariadb", "MariaDB"), ("mongodb", "MongoDB"), ("mysql", "MySQL"),
+  ("netbsd", "NetBSD"), ("oauth", "OAuth"), ("openbsd", "OpenBSD"), ("paypal", "PayPal"),
+  ("postgresql", "PostgreSQL"), ("powershell", "PowerShell"), ("sqlite", "SQLite"),
+  ("tvos", "tvOS"), ("typescript", "TypeScript"), ("watchos", "watchOS"),
+  ("webassembly", "WebAssembly"), ("websocket", "WebSocket"), ("wordpress", "WordPress"),
+  ("youtube", "YouTube"),
+];
+
+/// The spelling of a proper noun from the English rules written as the lower
+/// case word, if any.
+pub(crate) fn proper_noun(word: &str) -> Option<&'static str> {
+  PROPER_NOUNS
+  .binary_search_by_key(&word, |&(lower, _)| lower)
+  .ok()
+  .map(|i| PROPER_NOUNS[i].1)
+}
+
+/// Builds an inflector with the English rules.
+pub fn inflector() -> Inflector {
+  let mut inflector = Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES);
+  for &(singular, plural) in COMPOUNDS {
+    inflector = inflector.compound(singular, plural);
+  }
+  for &word in PREPOSITIONS {
+    inflector = inflector.preposition(word);
+  }
+  for &(_, noun) in PROPER_NOUNS {
+    inflector = inflector.proper_noun(noun);
+  }
+  inflector
+}
@@ -0,0 +1,117 @@
+//! The French inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "s"),
+  ("([sxz])$", "${1}"),
+  ("(au|eu)$", "${1}x"),
+  ("al$", "aux"),
+  ("(b|cor|ém|soupir|trav|vant|vitr)ail$", "${1}aux"),
+  ("^(bij|caill|ch|gen|hib|jouj|p)ou$", "${1}oux"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("s$", ""),
+  ("aux$", "al"),
+  ("(eu|eau)x$", "${1}"),
+  ("(b|cor|ém|soupir|trav|vant|vitr)aux$", "${1}ail"),
+  ("^(bij|caill|ch|gen|hib|jouj|p)oux$", "${1}ou"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("œil", "yeux"),
+  ("ciel", "cieux"),
+  ("aïeul", "aïeux"),
+  ("bal", "bals"),
+  ("carnaval", "carnavals"),
+  ("chacal", "chacals"),
+  ("festival", "festivals"),
+  ("récital", "récitals"),
+  ("régal", "régals"),
+  ("bleu", "bleus"),
+  ("pneu", "pneus"),
+  ("landau", "landaus"),
+  ("tuyau", "tuyaux"),
+  ("noyau", "noyaux"),
+  ("boyau", "boyaux"),
+  ("joyau", "joyaux"),
+  ("madame", "mesdames"),
+  ("mademoiselle", "mesdemoiselles"),
+  ("monsieur", "messieurs"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "avis",
+  "bras",
+  "corps",
+  "fils",
+  "fois",
+  "mois",
+  "pays",
+  "repas",
+  "souris",
+  "temps",
+];
+
+/// Builds an inflector with the French rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("chat", "chats"),
+    ("maison", "maisons"),
+    ("livre", "livres"),
+    ("gâteau", "gâteaux"),
+    ("bateau", "bateaux"),
+    ("château", "châteaux"),
+    ("jeu", "jeux"),
+    ("cheveu", "cheveux"),
+    ("cheval", "chevaux"),
+    ("journal", "journaux"),
+    ("animal", "animaux"),
+    ("travail", "travaux"),
+    ("vitrail", "vitraux"),
+    ("détail", "détails"),
+    ("bijou", "bijoux"),
+    ("genou", "genoux"),
+    ("clou", "clous"),
+    ("nez", "nez"),
+    ("prix", "prix"),
+    ("bras", "bras"),
+    ("temps", "temps"),
+    ("œil", "yeux"),
+    ("festival", "festivals"),
+    ("pneu", "pneus"),
+    ("tuyau", "tuyaux"),
+    ("monsieur", "messieurs"),
+    ("Château", "Châteaux"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
@@ -0,0 +1,150 @@
+//! The German inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "e"),
+  ("e$", "en"),
+  ("(el|er|chen|lein)$", "${1}"),
+  ("(ung|heit|keit|schaft|ion|tät|ik|ur|ei)$", "${1}en"),
+  ("(er|nd|zt|eg|ch)in$", "${1}innen"),
+  ("([aiouy])$", "${1}s"),
+  ("nis$", "nisse"),
+  ("um$", "en"),
+  ("(h)aus$", "${1}äuser"),
+  ("(m)ann$", "${1}änner"),
+  ("(b)uch$", "${1}ücher"),
+  ("(l)and$", "${1}änder"),
+  ("(s)tadt$", "${1}tädte"),
+  ("(b)aum$", "${1}äume"),
+  ("(z)ug$", "${1}üge"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("e$", ""),
+  ("en$", "e"),
+  ("(el|er|chen|lein)$", "${1}"),
+  ("(ung|heit|keit|schaft|ion|tät|ik|ur|ei)en$", "${1}"),
+  ("([aiouy])s$", "${1}"),
+  ("innen$", "in"),
+  ("nisse$", "nis"),
+  ("een$", "eum"),
+  ("(h)äuser$", "${1}aus"),
+  ("(m)änner$", "${1}ann"),
+  ("(b)ücher$", "${1}uch"),
+  ("(l)änder$", "${1}and"),
+  ("(s)tädte$", "${1}tadt"),
+  ("(b)äume$", "${1}aum"),
+  ("(z)üge$", "${1}ug"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+/// Most of these change their stem vowel into an umlaut.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("mutter", "mütter"),
+  ("vater", "väter"),
+  ("bruder", "brüder"),
+  ("tochter", "töchter"),
+  ("apfel", "äpfel"),
+  ("vogel", "vögel"),
+  ("garten", "gärten"),
+  ("kuh", "kühe"),
+  ("nacht", "nächte"),
+  ("hand", "hände"),
+  ("fuß", "füße"),
+  ("kopf", "köpfe"),
+  ("stuhl", "stühle"),
+  ("wort", "wörter"),
+  ("kind", "kinder"),
+  ("bild", "bilder"),
+  ("frau", "frauen"),
+  ("mensch", "menschen"),
+  ("herr", "herren"),
+  ("student", "studenten"),
+  ("name", "namen"),
+  ("tür", "türen"),
+  ("zahl", "zahlen"),
+  ("uhr", "uhren"),
+  ("bett", "betten"),
+  ("ohr", "ohren"),
+  ("staat", "staaten"),
+  ("datum", "daten"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "wagen",
+  "kuchen",
+  "zeichen",
+  "leben",
+  "essen",
+];
+
+/// Builds an inflector with the German rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("Tag", "Tage"),
+    ("Hund", "Hunde"),
+    ("Tisch", "Tische"),
+    ("Blume", "Blumen"),
+    ("Katze", "Katzen"),
+    ("Lehrer", "Lehrer"),
+    ("Onkel", "Onkel"),
+    ("Mädchen", "Mädchen"),
+    ("Wagen", "Wagen"),
+    ("Zeitung", "Zeitungen"),
+    ("Freiheit", "Freiheiten"),
+    ("Möglichkeit", "Möglichkeiten"),
+    ("Nation", "Nationen"),
+    ("Universität", "Universitäten"),
+    ("Lehrerin", "Lehrerinnen"),
+    ("Freundin", "Freundinnen"),
+    ("Auto", "Autos"),
+    ("Kino", "Kinos"),
+    ("Hobby", "Hobbys"),
+    ("Ergebnis", "Ergebnisse"),
+    ("Museum", "Museen"),
+    ("Haus", "Häuser"),
+    ("Rathaus", "Rathäuser"),
+    ("Mann", "Männer"),
+    ("Buch", "Bücher"),
+    ("Land", "Länder"),
+    ("Stadt", "Städte"),
+    ("Baum", "Bäume"),
+    ("Zug", "Züge"),
+    ("Mutter", "Mütter"),
+    ("Apfel", "Äpfel"),
+    ("Nacht", "Nächte"),
+    ("Kind", "Kinder"),
+    ("Frau", "Frauen"),
+    ("Student", "Studenten"),
+    ("Datum", "Daten"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
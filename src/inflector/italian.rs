@@ -0,0 +1,123 @@
+//! The Italian inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence. Words ending in a consonant are usually loanwords
+/// which do not change.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", ""),
+  ("o$", "i"),
+  ("a$", "e"),
+  ("e$", "i"),
+  ("ca$", "che"),
+  ("ga$", "ghe"),
+  ("co$", "chi"),
+  ("go$", "ghi"),
+  ("io$", "i"),
+  ("([aeiou][cg])ia$", "${1}ie"),
+  ("([^aeiou][cg])ia$", "${1}e"),
+  ("([iàèéìòù])$", "${1}"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("i$", "o"),
+  ("e$", "a"),
+  ("(ion|or|ent|al|il)i$", "${1}e"),
+  ("che$", "ca"),
+  ("ghe$", "ga"),
+  ("chi$", "co"),
+  ("ghi$", "go"),
+  ("([^z])zi$", "${1}zio"),
+  ("([aeiou][cg])ie$", "${1}ia"),
+  ("([^aeiou][cg])e$", "${1}ia"),
+  ("([àèéìòù])$", "${1}"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("uomo", "uomini"),
+  ("uovo", "uova"),
+  ("dio", "dei"),
+  ("bue", "buoi"),
+  ("mano", "mani"),
+  ("amico", "amici"),
+  ("medico", "medici"),
+  ("greco", "greci"),
+  ("zio", "zii"),
+  ("dito", "dita"),
+  ("braccio", "braccia"),
+  ("paio", "paia"),
+  ("ala", "ali"),
+  ("arma", "armi"),
+  ("problema", "problemi"),
+  ("cane", "cani"),
+  ("pane", "pani"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "crisi",
+  "tesi",
+  "analisi",
+  "serie",
+  "specie",
+  "re",
+  "gru",
+];
+
+/// Builds an inflector with the Italian rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("libro", "libri"),
+    ("gatto", "gatti"),
+    ("casa", "case"),
+    ("ragazza", "ragazze"),
+    ("fiore", "fiori"),
+    ("studente", "studenti"),
+    ("stazione", "stazioni"),
+    ("dottore", "dottori"),
+    ("animale", "animali"),
+    ("amica", "amiche"),
+    ("lago", "laghi"),
+    ("banco", "banchi"),
+    ("negozio", "negozi"),
+    ("camicia", "camicie"),
+    ("arancia", "arance"),
+    ("città", "città"),
+    ("caffè", "caffè"),
+    ("bar", "bar"),
+    ("film", "film"),
+    ("crisi", "crisi"),
+    ("uomo", "uomini"),
+    ("amico", "amici"),
+    ("uovo", "uova"),
+    ("cane", "cani"),
+    ("Libro", "Libri"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
@@ -0,0 +1,896 @@
+//! A configurable set of inflection rules. An `Inflector` owns its plural,
+//! singular, irregular, uncountable, and acronym rules so different parts of a
+//! program may use different vocabularies without sharing any global state.
+//!
+//! Rule sets are included for English, Spanish, French, German, Portuguese,
+//! Italian, and Dutch. The free functions in the `plural` module delegate to a
+//! default instance with the English rules.
+//!
+//! # Example
+//! ```rust
+//! use inflections::Inflector;
+//!
+//! let inflector = Inflector::english()
+//! .irregular("octopus", "octopodes")
+//! .uncountable("metadata");
+//!
+//! assert_eq!(inflector.pluralize("octopus"), "octopodes".to_owned());
+//! assert_eq!(inflector.pluralize("metadata"), "metadata".to_owned());
+//! assert_eq!(inflector.pluralize("post"), "posts".to_owned());
+//! ```
+
+mod dutch;
+mod english;
+mod french;
+mod german;
+mod italian;
+mod orm;
+mod portuguese;
+mod prose;
+mod rules;
+mod spanish;
+
+use std::sync::OnceLock;
+use regex::{Regex, RegexBuilder};
+use case::{is_separator, separate_keeping, to_sentence_case};
+use title::TitleStyle;
+use titlecase::to_titlecase;
+
+pub(crate) use self::english::proper_noun;
+pub use self::rules::{Forms, Rule, RuleSet, RulesError};
+
+/// A set of inflection rules. Rules registered later take precedence over
+/// rules registered earlier.
+///
+/// An `Inflector` is `Send` and `Sync`, so one configured instance may be
+/// shared between threads.
+#[derive(Clone, Debug)]
+pub struct Inflector {
+  /// Rules turning singular words into plural words.
+  plurals: Vec<(Regex, String)>,
+  /// Rules turning plural words into singular words.
+  singulars: Vec<(Regex, String)>,
+  /// Lower case `(singular, plural)` pairs which no rule can derive.
+  irregulars: Vec<(String, String)>,
+  /// Lower case words which are the same in the singular and the plural.
+  uncountables: Vec<String>,
+  /// Lower case `(singular, plural)` compound nouns which are not inflected
+  /// on their last word.
+  compounds: Vec<(String, String)>,
+  /// Lower case prepositions joining a compound noun’s head word to its
+  /// complement.
+  prepositions: Vec<String>,
+  /// Acronyms in the case they should be written.
+  acronyms: Vec<String>,
+  /// Proper nouns like brand names in the case they should be written.
+  proper_nouns: Vec<String>,
+  /// Rules turning identifiers into human readable text.
+  humans: Vec<(Regex, String)>,
+}
+
+impl Inflector {
+  /// Creates an inflector without any rules. Words are left as they are
+  /// until rules are registered.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::new().plural("$", "s");
+  /// assert_eq!(inflector.pluralize("cat"), "cats".to_owned());
+  /// ```
+  pub fn new() -> Self {
+    Inflector {
+      plurals: Vec::new(),
+      singulars: Vec::new(),
+      irregulars: Vec::new(),
+      uncountables: Vec::new(),
+      compounds: Vec::new(),
+      prepositions: Vec::new(),
+      acronyms: Vec::new(),
+      proper_nouns: Vec::new(),
+      humans: Vec::new(),
+    }
+  }
+
+  /// Creates an inflector with the rules for a locale, such as `"fr"` or
+  /// `"pt-BR"`. Only the language of the locale is considered. Returns `None`
+  /// if there are no rules for the language.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::for_locale("fr-CA").unwrap();
+  /// assert_eq!(inflector.pluralize("cheval"), "chevaux".to_owned());
+  /// assert!(Inflector::for_locale("tlh").is_none());
+  /// ```
+  pub fn for_locale(locale: &str) -> Option<Self> {
+    match language(locale).as_str() {
+      "en" => Some(Inflector::english()),
+      "es" => Some(Inflector::spanish()),
+      "fr" => Some(Inflector::french()),
+      "de" => Some(Inflector::german()),
+      "pt" => Some(Inflector::portuguese()),
+      "it" => Some(Inflector::italian()),
+      "nl" => Some(Inflector::dutch()),
+      _ => None,
+    }
+  }
+
+  /// Creates an inflector with the English rules. This is the same as
+  /// `Inflector::default()`.
+  pub fn english() -> Self {
+    english::inflector()
+  }
+
+  /// Creates an inflector with the Spanish rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::spanish();
+  /// assert_eq!(inflector.pluralize("luz"), "luces".to_owned());
+  /// assert_eq!(inflector.pluralize("canción"), "canciones".to_owned());
+  /// ```
+  pub fn spanish() -> Self {
+    spanish::inflector()
+  }
+
+  /// Creates an inflector with the French rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::french();
+  /// assert_eq!(inflector.pluralize("gâteau"), "gâteaux".to_owned());
+  /// assert_eq!(inflector.pluralize("journal"), "journaux".to_owned());
+  /// ```
+  pub fn french() -> Self {
+    french::inflector()
+  }
+
+  /// Creates an inflector with the German rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::german();
+  /// assert_eq!(inflector.pluralize("Zeitung"), "Zeitungen".to_owned());
+  /// assert_eq!(inflector.pluralize("Haus"), "Häuser".to_owned());
+  /// ```
+  pub fn german() -> Self {
+    german::inflector()
+  }
+
+  /// Creates an inflector with the Portuguese rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::portuguese();
+  /// assert_eq!(inflector.pluralize("avião"), "aviões".to_owned());
+  /// assert_eq!(inflector.pluralize("animal"), "animais".to_owned());
+  /// ```
+  pub fn portuguese() -> Self {
+    portuguese::inflector()
+  }
+
+  /// Creates an inflector with the Italian rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::italian();
+  /// assert_eq!(inflector.pluralize("libro"), "libri".to_owned());
+  /// assert_eq!(inflector.pluralize("amica"), "amiche".to_owned());
+  /// ```
+  pub fn italian() -> Self {
+    italian::inflector()
+  }
+
+  /// Creates an inflector with the Dutch rules.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::dutch();
+  /// assert_eq!(inflector.pluralize("boek"), "boeken".to_owned());
+  /// assert_eq!(inflector.pluralize("auto"), "auto's".to_owned());
+  /// ```
+  pub fn dutch() -> Self {
+    dutch::inflector()
+  }
+
+  /// Creates an inflector from tables of plural and singular rules, ordered
+  /// from the lowest to the highest precedence, irregular
+  /// `(singular, plural)` pairs, and uncountable words.
+  fn from_tables(
+    plurals: &[(&str, &str)],
+    singulars: &[(&str, &str)],
+    irregulars: &[(&str, &str)],
+    uncountables: &[&str],
+  ) -> Self {
+    let mut inflector = Inflector::new();
+    for &(rule, replacement) in plurals {
+      inflector = inflector.plural(rule, replacement);
+    }
+    for &(rule, replacement) in singulars {
+      inflector = inflector.singular(rule, replacement);
+    }
+    for &(singular, plural) in irregulars {
+      inflector = inflector.irregular(singular, plural);
+    }
+    for &word in uncountables {
+      inflector = inflector.uncountable(word);
+    }
+    inflector
+  }
+
+  /// Registers a rule turning singular words into plural words. The rule is
+  /// a case insensitive regular expression and the replacement may refer to
+  /// capture groups like `${1}`.
+  ///
+  /// # Panics
+  /// Panics if `rule` is not a valid regular expression.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().plural("(cact)us$", "${1}i");
+  /// assert_eq!(inflector.pluralize("Cactus"), "Cacti".to_owned());
+  /// ```
+  pub fn plural(mut self, rule: &str, replacement: &str) -> Self {
+    self.plurals.push((compile_or_panic(rule), replacement.to_owned()));
+    self
+  }
+
+  /// Registers a rule turning plural words into singular words. The rule is
+  /// a case insensitive regular expression and the replacement may refer to
+  /// capture groups like `${1}`.
+  ///
+  /// # Panics
+  /// Panics if `rule` is not a valid regular expression.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().singular("(cact)i$", "${1}us");
+  /// assert_eq!(inflector.singularize("cacti"), "cactus".to_owned());
+  /// ```
+  pub fn singular(mut self, rule: &str, replacement: &str) -> Self {
+    self.singulars.push((compile_or_panic(rule), replacement.to_owned()));
+    self
+  }
+
+  /// Registers a word with a plural form no rule can derive.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().irregular("octopus", "octopi");
+  /// assert_eq!(inflector.pluralize("octopus"), "octopi".to_owned());
+  /// assert_eq!(inflector.singularize("octopi"), "octopus".to_owned());
+  /// ```
+  pub fn irregular(mut self, singular: &str, plural: &str) -> Self {
+    let singular = singular.to_lowercase();
+    let plural = plural.to_lowercase();
+    self.uncountables.retain(|word| *word != singular && *word != plural);
+    self.irregulars.push((singular, plural));
+    self
+  }
+
+  /// Registers a word which is the same in the singular and the plural.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().uncountable("metadata");
+  /// assert_eq!(inflector.pluralize("metadata"), "metadata".to_owned());
+  /// assert_eq!(inflector.singularize("metadata"), "metadata".to_owned());
+  /// ```
+  pub fn uncountable(mut self, word: &str) -> Self {
+    self.uncountables.push(word.to_lowercase());
+    self
+  }
+
+  /// Registers a compound noun which is not inflected on its last word. The
+  /// words of the compound may be separated by any separator.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().compound("attorney general", "attorney generals");
+  /// assert_eq!(inflector.pluralize("Attorney-General"), "Attorney-Generals".to_owned());
+  /// ```
+  pub fn compound(mut self, singular: &str, plural: &str) -> Self {
+    self.compounds.push((singular.to_lowercase(), plural.to_lowercase()));
+    self
+  }
+
+  /// Registers a preposition which joins the head word of a compound noun to
+  /// its complement. The head word is the word right before the preposition.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().preposition("to");
+  /// assert_eq!(inflector.pluralize("heir-to-the-throne"), "heirs-to-the-throne".to_owned());
+  /// ```
+  pub fn preposition(mut self, word: &str) -> Self {
+    self.prepositions.push(word.to_lowercase());
+    self
+  }
+
+  /// Registers an acronym which keeps its case when converting to Title Case,
+  /// PascalCase, and camelCase.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().acronym("HTML");
+  /// assert_eq!(inflector.to_pascal_case("html_parser"), "HTMLParser".to_owned());
+  /// ```
+  pub fn acronym(mut self, acronym: &str) -> Self {
+    self.acronyms.push(acronym.to_owned());
+    self
+  }
+
+  /// Registers a proper noun, like a brand name, which keeps its canonical
+  /// spelling when converting to title case, prose sentence case, and human
+  /// readable text. The English rules include common names like “GitHub” and
+  /// “macOS.”
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().proper_noun("DuckDuckGo");
+  /// assert_eq!(inflector.to_title_case("duckduckgo search"), "DuckDuckGo Search".to_owned());
+  /// assert_eq!(inflector.to_title_case("iphone sync"), "iPhone Sync".to_owned());
+  /// assert_eq!(inflector.humanize("github_username"), "GitHub username".to_owned());
+  /// ```
+  pub fn proper_noun(mut self, noun: &str) -> Self {
+    self.proper_nouns.push(noun.to_owned());
+    self
+  }
+
+  /// Registers a rule turning an identifier into human readable text. The
+  /// rule is a case insensitive regular expression and the replacement may
+  /// refer to capture groups like `${1}`.
+  ///
+  /// # Panics
+  /// Panics if `rule` is not a valid regular expression.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().human("_cnt$", "_count");
+  /// assert_eq!(inflector.humanize("jobs_cnt"), "Jobs count".to_owned());
+  /// ```
+  pub fn human(mut self, rule: &str, replacement: &str) -> Self {
+    self.humans.push((compile_or_panic(rule), replacement.to_owned()));
+    self
+  }
+
+  /// Converts a singular noun into a plural noun.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.pluralize("post"), "posts".to_owned());
+  /// assert_eq!(inflector.pluralize("mother-in-law"), "mothers-in-law".to_owned());
+  /// ```
+  pub fn pluralize(&self, string: &str) -> String {
+    self.inflect(string, &[], Number::Plural)
+  }
+
+  /// Converts a plural noun into a singular noun.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.singularize("posts"), "post".to_owned());
+  /// assert_eq!(inflector.singularize("mothers-in-law"), "mother-in-law".to_owned());
+  /// ```
+  pub fn singularize(&self, string: &str) -> String {
+    self.inflect(string, &[], Number::Singular)
+  }
+
+  /// Turns an identifier into a human readable label. The first matching
+  /// human rule is applied, a trailing `_id` is removed, and the words are
+  /// written in sentence case with the first word capitalized and registered
+  /// acronyms and proper nouns kept.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english()
+  /// .acronym("SSN")
+  /// .human("^ssn$", "social security number");
+  ///
+  /// assert_eq!(inflector.humanize("ssn"), "Social security number".to_owned());
+  /// assert_eq!(inflector.humanize("employee_salary"), "Employee salary".to_owned());
+  /// assert_eq!(inflector.humanize("author_id"), "Author".to_owned());
+  /// assert_eq!(inflector.humanize("employee_ssn"), "Employee SSN".to_owned());
+  /// ```
+  pub fn humanize(&self, string: &str) -> String {
+    let string = match self.humans.iter().rev().find(|(rule, _)| rule.is_match(string)) {
+      Some((rule, replacement)) => rule.replace(string, replacement.as_str()).into_owned(),
+      None => string.to_owned(),
+    };
+    let sentence = to_sentence_case(&string);
+    let mut words: Vec<_> = sentence.split(' ').filter(|word| !word.is_empty()).collect();
+    if words.len() > 1 && words.last() == Some(&"id") {
+      words.pop();
+    }
+    words
+    .iter()
+    .enumerate()
+    .map(|(i, word)| match self.spelling_for(word) {
+      Some(spelling) => spelling.clone(),
+      None if i == 0 => capitalize(word),
+      None => (*word).to_owned(),
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+  }
+
+  /// Converts any case into Title Case keeping registered acronyms and proper
+  /// nouns.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().acronym("API");
+  /// assert_eq!(inflector.to_title_case("rest_api_client"), "Rest API Client".to_owned());
+  /// ```
+  pub fn to_title_case(&self, string: &str) -> String {
+    separate_keeping(string, ' ', |word| self.spelling_for(&word.to_lowercase()).is_some())
+    .to_lowercase()
+    .split(' ')
+    .map(|word| self.capitalize(word))
+    .collect::<Vec<_>>()
+    .join(" ")
+  }
+
+  /// Converts a heading into title case following a style guide, leaving
+  /// minor words in lower case and keeping registered acronyms and proper
+  /// nouns.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// # use inflections::title::TitleStyle;
+  /// let inflector = Inflector::english().acronym("API");
+  /// assert_eq!(inflector.titleize("a guide to the api", TitleStyle::Chicago), "A Guide to the API".to_owned());
+  /// ```
+  pub fn titleize(&self, string: &str, style: TitleStyle) -> String {
+    let spans = spans(string, char::is_whitespace);
+    let mut titled = String::with_capacity(string.len());
+    let mut end = 0;
+
+    for (i, &(start, stop)) in spans.iter().enumerate() {
+      titled.push_str(&string[end..start]);
+      end = stop;
+      let word = &string[start..stop];
+      // A subtitle starts after a colon, as does a sentence after a question.
+      let first = i == 0 || string[..start].trim_end().ends_with(is_title_break);
+      let last = i == spans.len() - 1 || word.ends_with(is_title_break);
+
+      let (before, core, after) = match split_punctuation(word) {
+        Some(split) => split,
+        None => {
+          titled.push_str(word);
+          continue;
+        },
+      };
+      titled.push_str(before);
+      let parts: Vec<_> = core.split('-').collect();
+      for (j, part) in parts.iter().enumerate() {
+        if j > 0 {
+          titled.push('-');
+        }
+        let lower = part.to_lowercase();
+        let forced = (first && j == 0) || (last && j == parts.len() - 1);
+        if !forced && style.is_minor(&lower) {
+          titled.push_str(&lower);
+        } else {
+          titled.push_str(&self.capitalize(&lower));
+        }
+      }
+      titled.push_str(after);
+    }
+
+    titled.push_str(&string[end..]);
+    titled
+  }
+
+  /// Converts any case into PascalCase keeping registered acronyms.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().acronym("API");
+  /// assert_eq!(inflector.to_pascal_case("rest_api_client"), "RestAPIClient".to_owned());
+  /// ```
+  pub fn to_pascal_case(&self, string: &str) -> String {
+    to_sentence_case(string)
+    .split(' ')
+    .map(|word| self.capitalize(word))
+    .collect()
+  }
+
+  /// Converts any case into camelCase keeping registered acronyms, except for
+  /// the first word which is always lower case.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().acronym("API");
+  /// assert_eq!(inflector.to_camel_case("rest_api_client"), "restAPIClient".to_owned());
+  /// assert_eq!(inflector.to_camel_case("api_client"), "apiClient".to_owned());
+  /// ```
+  pub fn to_camel_case(&self, string: &str) -> String {
+    to_sentence_case(string)
+    .split(' ')
+    .filter(|word| !word.is_empty())
+    .enumerate()
+    .map(|(i, word)| if i == 0 { word.to_owned() } else { self.capitalize(word) })
+    .collect()
+  }
+
+  /// Capitalizes a lower case word, or writes it in its registered spelling
+  /// if it is an acronym or a proper noun.
+  pub(crate) fn capitalize(&self, word: &str) -> String {
+    match self.spelling_for(word) {
+      Some(spelling) => spelling.clone(),
+      None => capitalize(word),
+    }
+  }
+
+  /// The registered acronym or proper noun written as the lower case word,
+  /// if any.
+  fn spelling_for(&self, word: &str) -> Option<&String> {
+    self.acronyms
+    .iter()
+    .rev()
+    .chain(self.proper_nouns.iter().rev())
+    .find(|spelling| spelling.len() == word.len() && spelling.chars().flat_map(char::to_lowercase).eq(word.chars()))
+  }
+
+  /// Inflects a whole string, which may contain many words, into `number`
+  /// consulting the `exceptions` table of `(singular, plural)` pairs before
+  /// the registered compound nouns.
+  pub(crate) fn inflect(&self, string: &str, exceptions: &[(&str, &str)], number: Number) -> String {
+    let words = words(string);
+
+    let (start, end) = match words.last() {
+      Some(&last) => last,
+      None => return string.to_owned(),
+    };
+
+    let compounds = self.compounds
+    .iter()
+    .rev()
+    .map(|(singular, plural)| (singular.as_str(), plural.as_str()));
+
+    for (singular, plural) in exceptions.iter().cloned().chain(compounds) {
+      let (from, to) = number.select(singular, plural);
+      // A string already in the target number must be left alone.
+      if ends_with_words(string, &words, to) {
+        return string.to_owned();
+      }
+      if let Some(result) = replace_words(string, &words, from, to) {
+        return result;
+      }
+    }
+
+    let (start, end) = self.head_word(string, &words).unwrap_or((start, end));
+    let mut result = String::with_capacity(string.len() + 2);
+    result.push_str(&string[..start]);
+    result.push_str(&self.inflect_word(&string[start..end], number));
+    result.push_str(&string[end..]);
+    result
+  }
+
+  /// Inflects a single word into `number` using the uncountable, irregular,
+  /// and regular rules in that order.
+  fn inflect_word(&self, word: &str, number: Number) -> String {
+    let lower = word.to_lowercase();
+
+    if self.uncountables.contains(&lower) {
+      return word.to_owned();
+    }
+
+    for (singular, plural) in self.irregulars.iter().rev() {
+      let (from, to) = number.select(singular, plural);
+      if lower == *to {
+        return word.to_owned();
+      }
+      if lower == *from {
+        return restore_case(word, to);
+      }
+    }
+
+    let rules = match number {
+      Number::Plural => &self.plurals,
+      Number::Singular => &self.singulars,
+    };
+
+    for (rule, replacement) in rules.iter().rev() {
+      if rule.is_match(word) {
+        let result = rule.replace(word, replacement.as_str()).into_owned();
+        return if is_all_upper(word) { result.to_uppercase() } else { result };
+      }
+    }
+
+    word.to_owned()
+  }
+
+  /// Finds the head word of a compound noun joined to its complement with a
+  /// preposition, i.e. “mother” in “mother-in-law.” Only hyphens and spaces
+  /// join such compounds, so identifiers like `sign_in_attempt` are inflected
+  /// on their last word.
+  fn head_word(&self, string: &str, words: &[(usize, usize)]) -> Option<(usize, usize)> {
+    let joined = |before: (usize, usize), after: (usize, usize)| !string[before.1..after.0].contains('_');
+    (1..words.len().saturating_sub(1))
+    .find(|&i| {
+      let (start, end) = words[i];
+      self.prepositions.contains(&string[start..end].to_lowercase()) &&
+      joined(words[i - 1], words[i]) &&
+      joined(words[i], words[i + 1])
+    })
+    .map(|i| words[i - 1])
+  }
+}
+
+impl Default for Inflector {
+  fn default() -> Self {
+    Inflector::english()
+  }
+}
+
+/// The default inflector with the English rules, used by the free functions.
+pub(crate) fn default_inflector() -> &'static Inflector {
+  static INFLECTOR: OnceLock<Inflector> = OnceLock::new();
+  INFLECTOR.get_or_init(Inflector::english)
+}
+
+/// The lower case language subtag of a locale like `"pt-BR"` or `"pt_BR"`.
+/// Every API taking a locale reads its language with this.
+pub(crate) fn language(locale: &str) -> String {
+  locale
+  .trim()
+  .split(['-', '_'])
+  .next()
+  .unwrap_or_default()
+  .to_lowercase()
+}
+
+/// The grammatical number a noun is being inflected into.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Number {
+  Singular,
+  Plural,
+}
+
+impl Number {
+  /// Orders a pair of `(singular, plural)` values as `(from, to)` for
+  /// inflecting into this number.
+  #[inline]
+  fn select<T>(self, singular: T, plural: T) -> (T, T) {
+    match self {
+      Number::Plural => (singular, plural),
+      Number::Singular => (plural, singular),
+    }
+  }
+}
+
+/// Compiles a case insensitive rule.
+fn compile(rule: &str) -> Result<Regex, ::regex::Error> {
+  RegexBuilder::new(rule).case_insensitive(true).build()
+}
+
+/// Compiles a case insensitive rule for the builder methods, which panic on
+/// invalid rules.
+fn compile_or_panic(rule: &str) -> Regex {
+  match compile(rule) {
+    Ok(regex) => regex,
+    Err(error) => panic!("invalid inflection rule `{}`: {}", rule, error),
+  }
+}
+
+/// Capitalizes the first letter of a word with its titlecase mapping.
+pub(crate) fn capitalize(word: &str) -> String {
+  let mut chars = word.chars();
+  chars
+  .next()
+  .map(|first| to_titlecase(first).chain(chars).collect())
+  .unwrap_or_default()
+}
+
+/// Splits a word into the punctuation before it, like an opening quote, its
+/// letters and digits, and the punctuation after it, so the punctuation can
+/// be kept as it is. Returns `None` if the word has no letters or digits.
+fn split_punctuation(word: &str) -> Option<(&str, &str, &str)> {
+  let start = word.len() - word.trim_start_matches(|c: char| !c.is_alphanumeric()).len();
+  let end = word.trim_end_matches(|c: char| !c.is_alphanumeric()).len();
+  if start >= end {
+    return None;
+  }
+  Some((&word[..start], &word[start..end], &word[end..]))
+}
+
+/// Finds the byte ranges of the runs of characters in a string which are not
+/// delimiters.
+pub(crate) fn spans<F: Fn(char) -> bool>(string: &str, is_delimiter: F) -> Vec<(usize, usize)> {
+  let mut spans = Vec::new();
+  let mut start = None;
+  for (i, c) in string.char_indices() {
+    match (start, is_delimiter(c)) {
+      (None, false) => start = Some(i),
+      (Some(s), true) => {
+        spans.push((s, i));
+        start = None;
+      },
+      _ => {},
+    }
+  }
+  if let Some(s) = start {
+    spans.push((s, string.len()));
+  }
+  spans
+}
+
+/// Checks if a character ends a title or a part of one, so the next word
+/// starts a subtitle.
+#[inline]
+fn is_title_break(c: char) -> bool {
+  c == ':' || c == '?' || c == '!' || c == '—'
+}
+
+/// Finds the byte ranges of all the words in a string, where words are
+/// delimited by separators.
+fn words(string: &str) -> Vec<(usize, usize)> {
+  spans(string, is_separator)
+}
+
+/// Checks if the last words of a string match the words of `entry` ignoring
+/// case and the kind of separator used.
+fn ends_with_words(string: &str, words: &[(usize, usize)], entry: &str) -> bool {
+  let entry: Vec<&str> = entry.split(is_separator).collect();
+  entry.len() <= words.len() &&
+  words[words.len() - entry.len()..]
+  .iter()
+  .zip(entry)
+  .all(|(&(start, end), word)| string[start..end].to_lowercase() == word.to_lowercase())
+}
+
+/// If the last words of a string match the words of `from`, replaces them
+/// with the words of `to` keeping the original separators and case.
+fn replace_words(string: &str, words: &[(usize, usize)], from: &str, to: &str) -> Option<String> {
+  if !ends_with_words(string, words, from) {
+    return None;
+  }
+
+  let count = from.split(is_separator).count();
+  let words = &words[words.len() - count..];
+  let to: Vec<&str> = to.split(is_separator).collect();
+  let mut result = String::with_capacity(string.len() + 2);
+  result.push_str(&string[..words[0].0]);
+
+  if to.len() == words.len() {
+    for (i, (&(start, end), word)) in words.iter().zip(to).enumerate() {
+      if i > 0 {
+        result.push_str(&string[words[i - 1].1..start]);
+      }
+      result.push_str(&restore_case(&string[start..end], word));
+    }
+  } else {
+    let original = &string[words[0].0..words[words.len() - 1].1];
+    result.push_str(&restore_case(original, &to.join(" ")));
+  }
+
+  result.push_str(&string[words[words.len() - 1].1..]);
+  Some(result)
+}
+
+/// Gives `replacement` the case of `original`, which is either all upper case,
+/// capitalized, or left as is.
+fn restore_case(original: &str, replacement: &str) -> String {
+  if is_all_upper(original) && original.chars().count() > 1 {
+    replacement.to_uppercase()
+  } else if original.chars().next().is_some_and(char::is_uppercase) {
+    capitalize(replacement)
+  } else {
+    replacement.to_owned()
+  }
+}
+
+/// Checks if a word has letters and all of them are upper case.
+fn is_all_upper(word: &str) -> bool {
+  word.chars().any(char::is_alphabetic) && !word.chars().any(char::is_lowercase)
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+  use std::thread;
+  use case::{is_title_case, to_title_case, CaseConverter, Locale};
+  use super::{proper_noun, Inflector};
+
+  #[test]
+  fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Inflector>();
+  }
+
+  #[test]
+  fn test_shared_between_threads() {
+    let inflector = Arc::new(Inflector::english().irregular("octopus", "octopi"));
+    let handles: Vec<_> = (0..4).map(|_| {
+      let inflector = inflector.clone();
+      thread::spawn(move || inflector.pluralize("octopus"))
+    }).collect();
+    for handle in handles {
+      assert_eq!(handle.join().unwrap(), "octopi");
+    }
+  }
+
+  #[test]
+  fn test_instances_are_independent() {
+    let a = Inflector::english().uncountable("sushi");
+    let b = Inflector::english();
+    assert_eq!(a.pluralize("sushi"), "sushi");
+    assert_eq!(b.pluralize("sushi"), "sushis");
+  }
+
+  #[test]
+  fn test_later_rules_take_precedence() {
+    let inflector = Inflector::english().plural("(cow)$", "kine").irregular("person", "persons");
+    assert_eq!(inflector.pluralize("cow"), "kine");
+    assert_eq!(inflector.pluralize("person"), "persons");
+  }
+
+  #[test]
+  fn test_irregular_overrides_uncountable() {
+    let inflector = Inflector::english().irregular("fish", "fishes");
+    assert_eq!(inflector.pluralize("fish"), "fishes");
+  }
+
+  #[test]
+  fn test_proper_nouns() {
+    for noun in &Inflector::english().proper_nouns {
+      assert_eq!(proper_noun(&noun.to_lowercase()), Some(noun.as_str()));
+    }
+    assert_eq!(proper_noun("iPhone"), None);
+    let title = Inflector::english().to_title_case("ios_and_macos");
+    assert_eq!(to_title_case("ios_and_macos"), title);
+    assert_eq!(CaseConverter::new(Locale::Root).to_title_case("ios_and_macos"), title);
+  }
+
+  #[test]
+  fn test_title_case_twice() {
+    let inflector = Inflector::english().proper_noun("DuckDuckGo");
+    let converter = CaseConverter::new(Locale::Root);
+    for &input in &["iphone sync", "GITHUB_ACTIONS", "push-to-gitHub", "iPhoneSync"] {
+      let title = to_title_case(input);
+      assert_eq!(to_title_case(&title), title);
+      assert!(is_title_case(&title));
+      let title = converter.to_title_case(input);
+      assert_eq!(converter.to_title_case(&title), title);
+      assert!(converter.is_title_case(&title));
+      let title = inflector.to_title_case(input);
+      assert_eq!(inflector.to_title_case(&title), title);
+    }
+    assert_eq!(to_title_case("iPhone Sync"), "iPhone Sync");
+    assert_eq!(inflector.to_title_case("DuckDuckGo on GitHub"), "DuckDuckGo On GitHub");
+  }
+
+  #[test]
+  fn test_empty() {
+    let inflector = Inflector::new();
+    assert_eq!(inflector.pluralize("cat"), "cat");
+    assert_eq!(inflector.singularize("cats"), "cats");
+  }
+}
@@ -0,0 +1,110 @@
+//! Names of database tables, model classes, and foreign keys, following the
+//! conventions of ORMs like Active Record.
+
+use case::to_snake_case;
+use super::Inflector;
+
+impl Inflector {
+  /// Converts a model name into the name of its table, which is the plural
+  /// of the name in snake_case.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.tableize("RawScaledScorer"), "raw_scaled_scorers".to_owned());
+  /// assert_eq!(inflector.tableize("Person"), "people".to_owned());
+  /// ```
+  pub fn tableize(&self, model: &str) -> String {
+    self.pluralize(&to_snake_case(model))
+  }
+
+  /// Converts a table name into the name of its model, which is the singular
+  /// of the name in PascalCase. A schema before a period, like `public.` in
+  /// `public.posts`, is removed.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.classify("egg_and_hams"), "EggAndHam".to_owned());
+  /// assert_eq!(inflector.classify("public.people"), "Person".to_owned());
+  /// ```
+  pub fn classify(&self, table: &str) -> String {
+    let table = table.rsplit('.').next().unwrap_or(table);
+    self.to_pascal_case(&self.singularize(&to_snake_case(table)))
+  }
+
+  /// Converts a model name into the name of a foreign key column referencing
+  /// its table.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.foreign_key("Message"), "message_id".to_owned());
+  /// assert_eq!(inflector.foreign_key("BlogPost"), "blog_post_id".to_owned());
+  /// ```
+  pub fn foreign_key(&self, model: &str) -> String {
+    format!("{}_id", to_snake_case(model))
+  }
+
+  /// Derives the name of the join table of a many-to-many association
+  /// between two models. The names of their tables are joined in
+  /// alphabetical order, and a prefix shared by both names is only written
+  /// once.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english();
+  /// assert_eq!(inflector.join_table("Product", "Category"), "categories_products".to_owned());
+  /// assert_eq!(inflector.join_table("CatalogProduct", "CatalogCategory"), "catalog_categories_products".to_owned());
+  /// ```
+  pub fn join_table(&self, model: &str, other: &str) -> String {
+    let mut tables = [self.tableize(model), self.tableize(other)];
+    tables.sort();
+    let [first, second] = tables;
+    // The shared prefix ends at a separator, so `catalog_` is shared by
+    // `catalog_categories` and `catalog_products` but `c` is not.
+    let shared = first
+    .char_indices()
+    .zip(second.chars())
+    .take_while(|&((_, a), b)| a == b)
+    .filter(|&((_, c), _)| c == '_')
+    .last()
+    .map_or(0, |((i, _), _)| i + 1);
+    format!("{}_{}", first, &second[shared..])
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::Inflector;
+
+  #[test]
+  fn test_tableize() {
+    let inflector = Inflector::english();
+    assert_eq!(inflector.tableize("ham_and_egg"), "ham_and_eggs");
+    assert_eq!(inflector.tableize("fancyCategory"), "fancy_categories");
+    assert_eq!(inflector.tableize("Sheep"), "sheep");
+    assert_eq!(inflector.tableize("SignInAttempt"), "sign_in_attempts");
+  }
+
+  #[test]
+  fn test_classify() {
+    let inflector = Inflector::english().acronym("API");
+    assert_eq!(inflector.classify("api_keys"), "APIKey");
+    assert_eq!(inflector.classify("business"), "Business");
+    assert_eq!(inflector.classify("schema.comments"), "Comment");
+  }
+
+  #[test]
+  fn test_join_table() {
+    let inflector = Inflector::english();
+    assert_eq!(inflector.join_table("Developer", "Project"), "developers_projects");
+    assert_eq!(inflector.join_table("Project", "Developer"), "developers_projects");
+    assert_eq!(inflector.join_table("Paper", "PaperBoy"), "paper_boys_papers");
+    assert_eq!(inflector.join_table("Tag", "Tag"), "tags_tags");
+  }
+}
@@ -0,0 +1,124 @@
+//! The Portuguese inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "s"),
+  ("([rz])$", "${1}es"),
+  ("([aeiou][sx])$", "${1}"),
+  ("ês$", "eses"),
+  ("m$", "ns"),
+  ("al$", "ais"),
+  ("el$", "éis"),
+  ("([áéíóú]v)el$", "${1}eis"),
+  ("ol$", "óis"),
+  ("ul$", "uis"),
+  ("il$", "is"),
+  ("ão$", "ões"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("s$", ""),
+  ("([rz])es$", "${1}"),
+  ("ns$", "m"),
+  ("([^aeiou])is$", "${1}il"),
+  ("ais$", "al"),
+  ("éis$", "el"),
+  ("([áéíóú]v)eis$", "${1}el"),
+  ("óis$", "ol"),
+  ("uis$", "ul"),
+  ("eses$", "ês"),
+  ("ões$", "ão"),
+  ("ães$", "ão"),
+  ("ãos$", "ão"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+/// Many nouns ending in “-ão” take “-ãos” or “-ães” instead of “-ões.”
+const IRREGULARS: &[(&str, &str)] = &[
+  ("mão", "mãos"),
+  ("irmão", "irmãos"),
+  ("cidadão", "cidadãos"),
+  ("cristão", "cristãos"),
+  ("órgão", "órgãos"),
+  ("bênção", "bênçãos"),
+  ("alemão", "alemães"),
+  ("pão", "pães"),
+  ("cão", "cães"),
+  ("capitão", "capitães"),
+  ("fóssil", "fósseis"),
+  ("réptil", "répteis"),
+  ("míssil", "mísseis"),
+  ("árvore", "árvores"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "lápis",
+  "ônibus",
+  "vírus",
+  "tênis",
+  "pires",
+  "tórax",
+];
+
+/// Builds an inflector with the Portuguese rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("casa", "casas"),
+    ("livro", "livros"),
+    ("flor", "flores"),
+    ("mar", "mares"),
+    ("luz", "luzes"),
+    ("homem", "homens"),
+    ("jardim", "jardins"),
+    ("som", "sons"),
+    ("animal", "animais"),
+    ("papel", "papéis"),
+    ("hotel", "hotéis"),
+    ("nível", "níveis"),
+    ("móvel", "móveis"),
+    ("farol", "faróis"),
+    ("azul", "azuis"),
+    ("funil", "funis"),
+    ("leão", "leões"),
+    ("avião", "aviões"),
+    ("canção", "canções"),
+    ("mão", "mãos"),
+    ("irmão", "irmãos"),
+    ("pão", "pães"),
+    ("alemão", "alemães"),
+    ("mês", "meses"),
+    ("inglês", "ingleses"),
+    ("lápis", "lápis"),
+    ("árvore", "árvores"),
+    ("Avião", "Aviões"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
@@ -0,0 +1,137 @@
+//! Sentence case for prose, which capitalizes the first word of every
+//! sentence instead of lower casing the whole text like `to_sentence_case`.
+
+use super::{capitalize, spans, split_punctuation, Inflector};
+
+/// Lower case abbreviations whose period does not end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+  "approx.", "cf.", "dept.", "dr.", "e.g.", "esp.", "est.", "etc.", "i.e.", "inc.", "jr.", "ltd.",
+  "mr.", "mrs.", "ms.", "mt.", "prof.", "sr.", "st.", "vol.", "vs.",
+];
+
+/// Lower case abbreviations which are also words, so they are only taken as
+/// abbreviations before a number as in “no. 5.”
+const NUMBERED_ABBREVIATIONS: &[&str] = &["fig.", "no."];
+
+/// Closing punctuation which may follow the end of a sentence.
+const CLOSERS: &[char] = &['"', '\'', ')', ']', '”', '’', '»'];
+
+impl Inflector {
+  /// Converts prose into sentence case. The first word of every sentence is
+  /// capitalized, other words are lower cased, registered acronyms and proper
+  /// nouns keep their spelling, and the pronoun “I” is kept in upper case.
+  /// Sentences end with `.`, `!`, `?`, or `…`, but not with the period of an
+  /// abbreviation like “e.g.” or an initial.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::english().acronym("API");
+  /// assert_eq!(
+  ///   inflector.to_prose_case("YOUR API KEY EXPIRED. renew it, e.g. in settings!"),
+  ///   "Your API key expired. Renew it, e.g. in settings!".to_owned(),
+  /// );
+  /// ```
+  pub fn to_prose_case(&self, string: &str) -> String {
+    let mut prose = String::with_capacity(string.len());
+    let mut end = 0;
+    let mut sentence_start = true;
+
+    let spans = spans(string, char::is_whitespace);
+    for (i, &(start, stop)) in spans.iter().enumerate() {
+      prose.push_str(&string[end..start]);
+      end = stop;
+      let word = &string[start..stop];
+
+      let split = split_punctuation(word);
+      if let Some((before, core, after)) = split {
+        let lower = core.to_lowercase();
+        let cased = match self.spelling_for(&lower) {
+          Some(spelling) => spelling.clone(),
+          None if sentence_start || lower == "i" || lower.starts_with("i'") || lower.starts_with("i’") => capitalize(&lower),
+          None => lower,
+        };
+        prose.push_str(before);
+        prose.push_str(&cased);
+        prose.push_str(after);
+      } else {
+        prose.push_str(word);
+      }
+
+      if split.is_some() || word.contains(is_sentence_end) {
+        let next = spans.get(i + 1).map(|&(start, stop)| &string[start..stop]);
+        sentence_start = ends_sentence(word, next);
+      }
+    }
+
+    prose.push_str(&string[end..]);
+    prose
+  }
+}
+
+/// Checks if a character may end a sentence.
+#[inline]
+fn is_sentence_end(c: char) -> bool {
+  c == '.' || c == '!' || c == '?' || c == '…'
+}
+
+/// Checks if a word ends the sentence it is in, given the word after it.
+fn ends_sentence(word: &str, next: Option<&str>) -> bool {
+  let word = word.trim_end_matches(CLOSERS);
+  if word.ends_with(['!', '?', '…']) || word.ends_with("...") {
+    return true;
+  }
+  if !word.ends_with('.') {
+    return false;
+  }
+  let word = word.trim_start_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+  // An initial like the “J.” in “J. R. R. Tolkien” is an abbreviation.
+  let initial = word.chars().count() == 2 && word.starts_with(char::is_alphabetic);
+  let numbered = NUMBERED_ABBREVIATIONS.contains(&word.as_str()) &&
+  next.is_some_and(|next| next.starts_with(|c: char| c.is_ascii_digit()));
+  !initial && !numbered && !ABBREVIATIONS.contains(&word.as_str())
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::Inflector;
+
+  const PROSE: &[(&str, &str)] = &[
+    ("hello world", "Hello world"),
+    ("HELLO. WORLD", "Hello. World"),
+    ("it works! does it? yes… great", "It works! Does it? Yes… Great"),
+    ("wait... what", "Wait... What"),
+    ("buy milk, eggs, etc. at the store", "Buy milk, eggs, etc. at the store"),
+    ("see dr. smith at 3.30 today", "See dr. smith at 3.30 today"),
+    ("the answer is no. we move on", "The answer is no. We move on"),
+    ("see no. 5 and fig. 2b. done", "See no. 5 and fig. 2b. Done"),
+    ("i ate a fig. it was ripe", "I ate a fig. It was ripe"),
+    ("\"stop.\" she said. \"now!\" he said", "\"Stop.\" She said. \"Now!\" He said"),
+    ("written by j. r. r. tolkien.", "Written by j. r. r. tolkien."),
+    ("i think i'm done", "I think I'm done"),
+    ("  spaced   out.  text ", "  Spaced   out.  Text "),
+    ("- a list item. another", "- A list item. Another"),
+    ("", ""),
+  ];
+
+  #[test]
+  fn test_prose_case() {
+    let inflector = Inflector::english();
+    for &(input, expected) in PROSE {
+      assert_eq!(inflector.to_prose_case(input), expected);
+    }
+  }
+
+  #[test]
+  fn test_acronyms() {
+    let inflector = Inflector::english().acronym("NASA").acronym("iOS");
+    assert_eq!(inflector.to_prose_case("nasa LAUNCHED. ios users rejoice"), "NASA launched. iOS users rejoice");
+  }
+
+  #[test]
+  fn test_proper_nouns() {
+    let inflector = Inflector::english().proper_noun("Acme");
+    assert_eq!(inflector.to_prose_case("PUSH TO GITHUB. then tell acme"), "Push to GitHub. Then tell Acme");
+    assert_eq!(Inflector::new().to_prose_case("push to github"), "Push to github");
+  }
+}
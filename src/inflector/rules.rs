@@ -0,0 +1,448 @@
+//! A declarative schema for the rules of an `Inflector`, so a vocabulary can
+//! be maintained in a TOML or JSON file instead of in Rust.
+//!
+//! Loading rules files requires the `toml` or `json` feature. Rules are
+//! written either as `{ rule = "…", replacement = "…" }` tables or as
+//! `["…", "…"]` pairs, and word forms either as `{ singular = "…", plural =
+//! "…" }` tables or as `["…", "…"]` pairs. A TOML rules file looks like this:
+//!
+//! ```toml
+//! uncountables = ["metadata"]
+//! acronyms = ["API"]
+//! proper_nouns = ["GitHub"]
+//! irregulars = [["schema", "schemata"]]
+//! humans = [["_cnt$", "_count"]]
+//!
+//! [[plurals]]
+//! rule = "(cact)us$"
+//! replacement = "${1}i"
+//! ```
+//!
+//! # Example
+//! ```rust
+//! use inflections::Inflector;
+//! use inflections::inflector::{Forms, RuleSet};
+//!
+//! let rules = RuleSet {
+//!   irregulars: vec![Forms::new("schema", "schemata")],
+//!   ..RuleSet::default()
+//! };
+//!
+//! let inflector = Inflector::english().with_rules(&rules).unwrap();
+//! assert_eq!(inflector.pluralize("schema"), "schemata".to_owned());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+#[cfg(any(feature = "toml", feature = "json"))]
+use std::fs;
+#[cfg(any(feature = "toml", feature = "json"))]
+use std::path::Path;
+use std::path::PathBuf;
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+use super::{compile, Inflector};
+
+/// A set of inflection rules which may be added to an `Inflector` with
+/// `Inflector::with_rules`. Every field is optional in a rules file.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct RuleSet {
+  /// Rules turning singular words into plural words.
+  pub plurals: Vec<Rule>,
+  /// Rules turning plural words into singular words.
+  pub singulars: Vec<Rule>,
+  /// Words with a plural form no rule can derive.
+  pub irregulars: Vec<Forms>,
+  /// Words which are the same in the singular and the plural.
+  pub uncountables: Vec<String>,
+  /// Compound nouns which are not inflected on their last word.
+  pub compounds: Vec<Forms>,
+  /// Prepositions joining a compound noun’s head word to its complement.
+  pub prepositions: Vec<String>,
+  /// Acronyms in the case they should be written.
+  pub acronyms: Vec<String>,
+  /// Proper nouns like brand names in the case they should be written.
+  pub proper_nouns: Vec<String>,
+  /// Rules turning identifiers into human readable text.
+  pub humans: Vec<Rule>,
+}
+
+/// A case insensitive regular expression and its replacement.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct Rule {
+  /// The regular expression to match.
+  pub rule: String,
+  /// The replacement, which may refer to capture groups like `${1}`.
+  pub replacement: String,
+  /// The line of the rules file the rule was defined on, if known.
+  #[cfg_attr(feature = "serde", serde(skip))]
+  pub line: Option<usize>,
+}
+
+impl Rule {
+  /// Creates a rule which was not loaded from a file.
+  pub fn new(rule: &str, replacement: &str) -> Self {
+    Rule {
+      rule: rule.to_owned(),
+      replacement: replacement.to_owned(),
+      line: None,
+    }
+  }
+}
+
+/// The singular and plural forms of a word.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct Forms {
+  /// The singular form.
+  pub singular: String,
+  /// The plural form.
+  pub plural: String,
+}
+
+impl Forms {
+  /// Creates the forms of a word.
+  pub fn new(singular: &str, plural: &str) -> Self {
+    Forms {
+      singular: singular.to_owned(),
+      plural: plural.to_owned(),
+    }
+  }
+}
+
+/// An error loading a set of inflection rules.
+#[derive(Debug)]
+pub enum RulesError {
+  /// The rules file could not be read.
+  Io(io::Error),
+  /// The rules file is malformed or does not match the schema.
+  Parse {
+    /// The line the error was found on, if known.
+    line: Option<usize>,
+    /// A description of the error.
+    message: String,
+  },
+  /// A rule is not a valid regular expression.
+  InvalidRule {
+    /// The line the rule was defined on, if known.
+    line: Option<usize>,
+    /// The invalid rule.
+    rule: String,
+    /// A description of the error.
+    message: String,
+  },
+  /// The format of the rules file could not be told from its extension, or
+  /// support for the format is not enabled.
+  UnknownFormat(PathBuf),
+}
+
+impl RulesError {
+  /// The line of the rules file the error was found on, if known.
+  pub fn line(&self) -> Option<usize> {
+    match *self {
+      RulesError::Parse { line, .. } | RulesError::InvalidRule { line, .. } => line,
+      RulesError::Io(_) | RulesError::UnknownFormat(_) => None,
+    }
+  }
+}
+
+impl fmt::Display for RulesError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(line) = self.line() {
+      write!(f, "line {}: ", line)?;
+    }
+    match *self {
+      RulesError::Io(ref error) => write!(f, "could not read rules file: {}", error),
+      RulesError::Parse { ref message, .. } => write!(f, "invalid rules file: {}", message),
+      RulesError::InvalidRule { ref rule, ref message, .. } => write!(f, "invalid rule `{}`: {}", rule, message),
+      RulesError::UnknownFormat(ref path) => write!(f, "unknown rules file format: {}", path.display()),
+    }
+  }
+}
+
+impl Error for RulesError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match *self {
+      RulesError::Io(ref error) => Some(error),
+      _ => None,
+    }
+  }
+}
+
+impl RuleSet {
+  /// Parses a set of rules from TOML.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::inflector::RuleSet;
+  /// let rules = RuleSet::from_toml("irregulars = [[\"schema\", \"schemata\"]]").unwrap();
+  /// assert_eq!(rules.irregulars[0].plural, "schemata".to_owned());
+  /// ```
+  #[cfg(feature = "toml")]
+  pub fn from_toml(source: &str) -> Result<Self, RulesError> {
+    let mut rules: RuleSet = ::toml::from_str(source).map_err(|error| RulesError::Parse {
+      line: error.span().map(|span| line_of(source, span.start)),
+      message: error.message().to_owned(),
+    })?;
+    rules.locate(source);
+    Ok(rules)
+  }
+
+  /// Parses a set of rules from JSON.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::inflector::RuleSet;
+  /// let rules = RuleSet::from_json(r#"{ "uncountables": ["metadata"] }"#).unwrap();
+  /// assert_eq!(rules.uncountables, vec!["metadata".to_owned()]);
+  /// ```
+  #[cfg(feature = "json")]
+  pub fn from_json(source: &str) -> Result<Self, RulesError> {
+    let mut rules: RuleSet = ::serde_json::from_str(source).map_err(|error| RulesError::Parse {
+      line: if error.line() > 0 { Some(error.line()) } else { None },
+      message: error.to_string(),
+    })?;
+    rules.locate(source);
+    Ok(rules)
+  }
+
+  /// Reads a set of rules from a file, choosing the format from the file’s
+  /// `.toml` or `.json` extension.
+  #[cfg(any(feature = "toml", feature = "json"))]
+  pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, RulesError> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path).map_err(RulesError::Io)?;
+    match path.extension().and_then(|extension| extension.to_str()) {
+      #[cfg(feature = "toml")]
+      Some("toml") => RuleSet::from_toml(&source),
+      #[cfg(feature = "json")]
+      Some("json") => RuleSet::from_json(&source),
+      _ => Err(RulesError::UnknownFormat(path.to_owned())),
+    }
+  }
+
+  /// Fills in the line of every rule by finding its quoted text in the source
+  /// it was parsed from. Each list of rules is searched in order, so a rule is
+  /// always found after the rule before it.
+  #[cfg(any(feature = "toml", feature = "json"))]
+  fn locate(&mut self, source: &str) {
+    for rules in [&mut self.plurals, &mut self.singulars, &mut self.humans] {
+      let mut from = 0;
+      for rule in rules.iter_mut() {
+        if let Some(offset) = find_quoted(source, from, &rule.rule) {
+          rule.line = Some(line_of(source, offset));
+          from = offset + 1;
+        }
+      }
+    }
+  }
+}
+
+impl Inflector {
+  /// Registers every rule in a set of rules after the rules already
+  /// registered, so they take precedence.
+  ///
+  /// # Errors
+  /// Returns `RulesError::InvalidRule` if a rule is not a valid regular
+  /// expression, in which case no rules are registered.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// # use inflections::inflector::{Rule, RuleSet};
+  /// let rules = RuleSet {
+  ///   plurals: vec![Rule::new("(cact)us$", "${1}i")],
+  ///   ..RuleSet::default()
+  /// };
+  /// let inflector = Inflector::english().with_rules(&rules).unwrap();
+  /// assert_eq!(inflector.pluralize("cactus"), "cacti".to_owned());
+  ///
+  /// let rules = RuleSet {
+  ///   plurals: vec![Rule::new("(cact", "${1}i")],
+  ///   ..RuleSet::default()
+  /// };
+  /// assert!(Inflector::english().with_rules(&rules).is_err());
+  /// ```
+  pub fn with_rules(mut self, rules: &RuleSet) -> Result<Self, RulesError> {
+    let plurals = compile_all(&rules.plurals)?;
+    let singulars = compile_all(&rules.singulars)?;
+    let humans = compile_all(&rules.humans)?;
+
+    self.plurals.extend(plurals);
+    self.singulars.extend(singulars);
+    self.humans.extend(humans);
+    for forms in &rules.irregulars {
+      self = self.irregular(&forms.singular, &forms.plural);
+    }
+    for word in &rules.uncountables {
+      self = self.uncountable(word);
+    }
+    for forms in &rules.compounds {
+      self = self.compound(&forms.singular, &forms.plural);
+    }
+    for word in &rules.prepositions {
+      self = self.preposition(word);
+    }
+    for acronym in &rules.acronyms {
+      self = self.acronym(acronym);
+    }
+    for noun in &rules.proper_nouns {
+      self = self.proper_noun(noun);
+    }
+    Ok(self)
+  }
+
+  /// Creates an inflector with the English rules and the rules in a TOML
+  /// string.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::from_toml("irregulars = [[\"schema\", \"schemata\"]]").unwrap();
+  /// assert_eq!(inflector.pluralize("schema"), "schemata".to_owned());
+  /// assert_eq!(inflector.pluralize("post"), "posts".to_owned());
+  /// ```
+  #[cfg(feature = "toml")]
+  pub fn from_toml(source: &str) -> Result<Self, RulesError> {
+    Inflector::english().with_rules(&RuleSet::from_toml(source)?)
+  }
+
+  /// Creates an inflector with the English rules and the rules in a JSON
+  /// string.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::Inflector;
+  /// let inflector = Inflector::from_json(r#"{ "irregulars": [["schema", "schemata"]] }"#).unwrap();
+  /// assert_eq!(inflector.pluralize("schema"), "schemata".to_owned());
+  /// ```
+  #[cfg(feature = "json")]
+  pub fn from_json(source: &str) -> Result<Self, RulesError> {
+    Inflector::english().with_rules(&RuleSet::from_json(source)?)
+  }
+
+  /// Creates an inflector with the English rules and the rules in a TOML or
+  /// JSON file.
+  #[cfg(any(feature = "toml", feature = "json"))]
+  pub fn from_rules_file<P: AsRef<Path>>(path: P) -> Result<Self, RulesError> {
+    Inflector::english().with_rules(&RuleSet::from_file(path)?)
+  }
+}
+
+/// Compiles a list of rules, failing on the first invalid rule.
+fn compile_all(rules: &[Rule]) -> Result<Vec<(Regex, String)>, RulesError> {
+  rules
+  .iter()
+  .map(|rule| match compile(&rule.rule) {
+    Ok(regex) => Ok((regex, rule.replacement.clone())),
+    Err(error) => Err(RulesError::InvalidRule {
+      line: rule.line,
+      rule: rule.rule.clone(),
+      message: error.to_string(),
+    }),
+  })
+  .collect()
+}
+
+/// Finds the byte offset of `value` written as a quoted string in TOML or
+/// JSON source, starting the search at `from`.
+#[cfg(any(feature = "toml", feature = "json"))]
+fn find_quoted(source: &str, from: usize, value: &str) -> Option<usize> {
+  let basic = format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+  let literal = format!("'{}'", value);
+  [basic, literal]
+  .iter()
+  .filter_map(|needle| source[from..].find(needle.as_str()))
+  .min()
+  .map(|offset| from + offset)
+}
+
+/// The one based line number of a byte offset.
+#[cfg(any(feature = "toml", feature = "json"))]
+fn line_of(source: &str, offset: usize) -> usize {
+  source[..offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod test {
+  use super::{Rule, RuleSet, RulesError};
+  use super::super::Inflector;
+
+  #[test]
+  fn test_invalid_rule() {
+    let rules = RuleSet {
+      singulars: vec![Rule { line: Some(7), ..Rule::new("(cact", "${1}us") }],
+      ..RuleSet::default()
+    };
+    match Inflector::english().with_rules(&rules) {
+      Err(RulesError::InvalidRule { line, ref rule, .. }) => {
+        assert_eq!(line, Some(7));
+        assert_eq!(rule, "(cact");
+      },
+      result => panic!("unexpected result {:?}", result.map(|_| ())),
+    }
+  }
+
+  #[cfg(feature = "toml")]
+  #[test]
+  fn test_toml() {
+    let source = r#"
+uncountables = ["metadata"]
+acronyms = ["API"]
+proper_nouns = ["InfluxDB"]
+irregulars = [{ singular = "schema", plural = "schemata" }]
+humans = [["_cnt$", "_count"]]
+
+[[plurals]]
+rule = '(cact)us$'
+replacement = "${1}i"
+"#;
+    let inflector = Inflector::from_toml(source).unwrap();
+    assert_eq!(inflector.pluralize("metadata"), "metadata");
+    assert_eq!(inflector.pluralize("schema"), "schemata");
+    assert_eq!(inflector.pluralize("cactus"), "cacti");
+    assert_eq!(inflector.humanize("jobs_cnt"), "Jobs count");
+    assert_eq!(inflector.to_pascal_case("api_client"), "APIClient");
+    assert_eq!(inflector.to_title_case("influxdb_stats"), "InfluxDB Stats");
+  }
+
+  #[cfg(feature = "toml")]
+  #[test]
+  fn test_toml_errors() {
+    let source = "plurals = [\n  [\"(quiz)$\", \"${1}zes\"],\n  [\"(cact\", \"${1}i\"],\n]\n";
+    let error = Inflector::from_toml(source).unwrap_err();
+    assert_eq!(error.line(), Some(3));
+    assert!(error.to_string().starts_with("line 3: invalid rule `(cact`"));
+
+    let error = Inflector::from_toml("uncountables = [\n  1\n]").unwrap_err();
+    assert_eq!(error.line(), Some(2));
+
+    let error = Inflector::from_toml("unknown = true").unwrap_err();
+    assert_eq!(error.line(), Some(1));
+  }
+
+  #[cfg(feature = "json")]
+  #[test]
+  fn test_json() {
+    let source = r#"{
+  "singulars": [
+    { "rule": "(cact)i$", "replacement": "${1}us" },
+    { "rule": "\\d+(", "replacement": "" }
+  ]
+}"#;
+    let error = Inflector::from_json(source).unwrap_err();
+    assert_eq!(error.line(), Some(4));
+
+    let rules = RuleSet::from_json(r#"{ "singulars": [["(cact)i$", "${1}us"]] }"#).unwrap();
+    let inflector = Inflector::english().with_rules(&rules).unwrap();
+    assert_eq!(inflector.singularize("cacti"), "cactus");
+
+    let error = RuleSet::from_json("{\n  \"plurals\": 1\n}").unwrap_err();
+    assert_eq!(error.line(), Some(2));
+  }
+}
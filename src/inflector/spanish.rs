@@ -0,0 +1,129 @@
+//! The Spanish inflection rules.
+
+use super::Inflector;
+
+/// Rules used to turn a singular word into a plural word, from the lowest to
+/// the highest precedence.
+const PLURALS: &[(&str, &str)] = &[
+  ("$", "s"),
+  ("([^aeiouáéíóú])$", "${1}es"),
+  ("([íú])$", "${1}es"),
+  ("z$", "ces"),
+  ("([aeiou][sx])$", "${1}"),
+  ("ás$", "ases"),
+  ("és$", "eses"),
+  ("ís$", "ises"),
+  ("ós$", "oses"),
+  ("ús$", "uses"),
+  ("án$", "anes"),
+  ("én$", "enes"),
+  ("ín$", "ines"),
+  ("ón$", "ones"),
+  ("ún$", "unes"),
+];
+
+/// Rules used to turn a plural word into a singular word, from the lowest to
+/// the highest precedence.
+const SINGULARS: &[(&str, &str)] = &[
+  ("s$", ""),
+  ("([aeiou][lrndjy])es$", "${1}"),
+  ("([aeiou])ces$", "${1}z"),
+  ("([íú])es$", "${1}"),
+  ("anes$", "án"),
+  ("enes$", "én"),
+  ("ines$", "ín"),
+  ("ones$", "ón"),
+  ("unes$", "ún"),
+  ("eses$", "és"),
+  ("uses$", "ús"),
+  ("^([^aeiouáéíóú]*[aeiou]n)es$", "${1}"),
+];
+
+/// Words with a plural form no rule can derive, as `(singular, plural)` pairs.
+const IRREGULARS: &[(&str, &str)] = &[
+  ("examen", "exámenes"),
+  ("imagen", "imágenes"),
+  ("joven", "jóvenes"),
+  ("origen", "orígenes"),
+  ("volumen", "volúmenes"),
+  ("régimen", "regímenes"),
+  ("carácter", "caracteres"),
+  ("país", "países"),
+  ("mes", "meses"),
+  ("gas", "gases"),
+  ("dios", "dioses"),
+];
+
+/// Words which are the same in both the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+  "lunes",
+  "martes",
+  "miércoles",
+  "jueves",
+  "viernes",
+  "análisis",
+  "crisis",
+  "tesis",
+  "virus",
+  "tórax",
+  "cumpleaños",
+  "paraguas",
+];
+
+/// Builds an inflector with the Spanish rules.
+pub fn inflector() -> Inflector {
+  Inflector::from_tables(PLURALS, SINGULARS, IRREGULARS, UNCOUNTABLES)
+}
+
+#[cfg(test)]
+mod test {
+  use super::inflector;
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("casa", "casas"),
+    ("coche", "coches"),
+    ("padre", "padres"),
+    ("calle", "calles"),
+    ("clase", "clases"),
+    ("árbol", "árboles"),
+    ("mujer", "mujeres"),
+    ("ciudad", "ciudades"),
+    ("reloj", "relojes"),
+    ("rey", "reyes"),
+    ("luz", "luces"),
+    ("pez", "peces"),
+    ("lápiz", "lápices"),
+    ("canción", "canciones"),
+    ("león", "leones"),
+    ("jardín", "jardines"),
+    ("alemán", "alemanes"),
+    ("inglés", "ingleses"),
+    ("autobús", "autobuses"),
+    ("rubí", "rubíes"),
+    ("pan", "panes"),
+    ("tren", "trenes"),
+    ("examen", "exámenes"),
+    ("joven", "jóvenes"),
+    ("país", "países"),
+    ("mes", "meses"),
+    ("lunes", "lunes"),
+    ("crisis", "crisis"),
+    ("Ciudad", "Ciudades"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.pluralize(singular), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    let inflector = inflector();
+    for &(singular, plural) in NOUNS {
+      assert_eq!(inflector.singularize(plural), singular);
+    }
+  }
+}
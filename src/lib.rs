@@ -10,10 +10,36 @@
 //! assert_eq!("Hello World".to_camel_case(), "helloWorld".to_owned());
 //! ```
 
+extern crate regex;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "toml")]
+extern crate toml;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
+
 pub mod case;
+pub mod inflector;
+pub mod markup;
+pub mod name;
+pub mod ordinal;
+pub mod orm;
+pub mod path;
+pub mod plural;
+pub mod plural_rules;
+pub mod quantity;
+pub mod slug;
+pub mod title;
+mod titlecase;
+pub mod transliterate;
+pub mod words;
+
+pub use inflector::Inflector;
 
-/// An extension trait to make the functions in the `case` module available as
-/// methods on the `str` type.
+/// An extension trait to make the functions in the `case`, `name`, `plural`,
+/// `quantity`, and `slug` modules available as methods on the `str` type.
 ///
 /// # Example
 ///
@@ -51,6 +77,13 @@ pub trait Inflect {
   fn is_snake_case(&self) -> bool;
   fn to_constant_case(&self) -> String;
   fn is_constant_case(&self) -> bool;
+  fn to_human_case(&self) -> String;
+  fn to_prose_case(&self) -> String;
+  fn to_name_case(&self) -> String;
+  fn to_plural(&self) -> String;
+  fn to_singular(&self) -> String;
+  fn to_quantity(&self, count: usize) -> String;
+  fn to_slug(&self) -> String;
 }
 
 impl Inflect for str {
@@ -74,6 +107,13 @@ impl Inflect for str {
   #[inline] fn is_snake_case(&self) -> bool { case::is_snake_case(self) }
   #[inline] fn to_constant_case(&self) -> String { case::to_constant_case(self) }
   #[inline] fn is_constant_case(&self) -> bool { case::is_constant_case(self) }
+  #[inline] fn to_human_case(&self) -> String { case::humanize(self) }
+  #[inline] fn to_prose_case(&self) -> String { case::to_prose_case(self) }
+  #[inline] fn to_name_case(&self) -> String { name::name_case(self) }
+  #[inline] fn to_plural(&self) -> String { plural::pluralize(self) }
+  #[inline] fn to_singular(&self) -> String { plural::singularize(self) }
+  #[inline] fn to_quantity(&self, count: usize) -> String { quantity::quantify(count, self) }
+  #[inline] fn to_slug(&self) -> String { slug::slugify(self) }
 }
 
 #[cfg(test)]
@@ -89,4 +129,36 @@ mod test {
   fn test_string() {
     assert_eq!("foo".to_owned().to_title_case(), "Foo".to_owned());
   }
+
+  #[test]
+  fn test_human_case() {
+    assert_eq!("employee_salary".to_human_case(), "Employee salary".to_owned());
+  }
+
+  #[test]
+  fn test_prose_case() {
+    assert_eq!("HELLO. WORLD".to_prose_case(), "Hello. World".to_owned());
+  }
+
+  #[test]
+  fn test_name_case() {
+    assert_eq!("RONALD MCDONALD".to_name_case(), "Ronald McDonald".to_owned());
+  }
+
+  #[test]
+  fn test_plural() {
+    assert_eq!("mother-in-law".to_plural(), "mothers-in-law".to_owned());
+    assert_eq!("mothers-in-law".to_singular(), "mother-in-law".to_owned());
+  }
+
+  #[test]
+  fn test_quantity() {
+    assert_eq!("file".to_quantity(1), "1 file".to_owned());
+    assert_eq!("file".to_quantity(3), "3 files".to_owned());
+  }
+
+  #[test]
+  fn test_slug() {
+    assert_eq!("Crème Brûlée".to_slug(), "creme-brulee".to_owned());
+  }
 }
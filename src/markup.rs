@@ -0,0 +1,575 @@
+//! Utilities to convert the case of the text in Markdown and HTML documents
+//! without rewriting their markup. A conversion like `to_title_case` is
+//! applied to every run of text, while code spans, fenced code blocks, link
+//! targets, URLs, tags, and entities are left as they are.
+//!
+//! Code is passed to the conversion as a placeholder, so conversions which
+//! treat the first and last words of their input differently, like
+//! `titleize`, see the words around it in their place. Other markup, like a
+//! tag or a link, separates runs of text which are converted on their own.
+//!
+//! # Example
+//! ```rust
+//! use inflections::markup::convert_markdown_headings;
+//! use inflections::title::{titleize, TitleStyle};
+//!
+//! let markdown = "# a guide to `serde` in rust\n\nsome text\n";
+//! assert_eq!(
+//!   convert_markdown_headings(markdown, |text| titleize(text, TitleStyle::Chicago)),
+//!   "# A Guide to `serde` in Rust\n\nsome text\n".to_owned(),
+//! );
+//! ```
+
+use std::mem;
+
+/// The placeholder code is replaced with while its text is converted.
+const PLACEHOLDER: char = '\u{fffc}';
+
+/// Converts the text of a Markdown document, leaving code spans, fenced and
+/// indented code blocks, link targets, URLs, HTML tags, entities, and Markdown
+/// syntax untouched.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::to_upper_case;
+/// # use inflections::markup::convert_markdown;
+/// let markdown = "see [the docs](https://example.com/docs) &amp; run `cargo test`";
+/// assert_eq!(
+///   convert_markdown(markdown, to_upper_case),
+///   "SEE [THE DOCS](https://example.com/docs) &amp; RUN `cargo test`".to_owned(),
+/// );
+/// ```
+pub fn convert_markdown<F: Fn(&str) -> String>(markdown: &str, convert: F) -> String {
+  convert_markdown_lines(markdown, &convert, |_| true)
+}
+
+/// Converts the text of the ATX headings, like `# Heading`, of a Markdown
+/// document the same way as `convert_markdown`. Other lines are left as they
+/// are.
+///
+/// # Example
+/// ```rust
+/// # use inflections::markup::convert_markdown_headings;
+/// # use inflections::title::{titleize, TitleStyle};
+/// let markdown = "## the rise of async in rust\n```\n# not a heading\n```\n";
+/// assert_eq!(
+///   convert_markdown_headings(markdown, |text| titleize(text, TitleStyle::Chicago)),
+///   "## The Rise of Async in Rust\n```\n# not a heading\n```\n".to_owned(),
+/// );
+/// ```
+pub fn convert_markdown_headings<F: Fn(&str) -> String>(markdown: &str, convert: F) -> String {
+  convert_markdown_lines(markdown, &convert, is_heading)
+}
+
+/// Converts the text of an HTML document, leaving tags, comments, entities,
+/// and the content of `code`, `pre`, `kbd`, `samp`, `script`, `style`, and
+/// `textarea` elements untouched.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::to_title_case;
+/// # use inflections::markup::convert_html;
+/// let html = r#"<h1 class="page-title">getting started &mdash; <code>cargo new</code></h1>"#;
+/// assert_eq!(
+///   convert_html(html, to_title_case),
+///   r#"<h1 class="page-title">Getting Started &mdash; <code>cargo new</code></h1>"#.to_owned(),
+/// );
+/// ```
+pub fn convert_html<F: Fn(&str) -> String>(html: &str, convert: F) -> String {
+  let mut converted = Text::new(html, &convert);
+  let mut i = 0;
+
+  while i < html.len() {
+    let rest = &html[i..];
+    let markup = if rest.starts_with("<!--") {
+      Some(rest.find("-->").map_or(rest.len(), |end| end + 3))
+    } else if rest.starts_with('<') {
+      match tag_len(rest) {
+        Some(len) => match raw_element_len(rest, len) {
+          Some(raw) => {
+            converted.code(i, i + raw);
+            i += raw;
+            continue;
+          },
+          None => Some(len),
+        },
+        None => None,
+      }
+    } else if rest.starts_with('&') {
+      entity_len(rest)
+    } else {
+      None
+    };
+
+    match markup {
+      Some(len) => {
+        converted.markup(i, i + len);
+        i += len;
+      },
+      None => i += rest.chars().next().map_or(1, char::len_utf8),
+    }
+  }
+
+  converted.finish()
+}
+
+/// Accumulates a converted document, passing runs of text through a
+/// conversion and copying markup as it is.
+struct Text<'a, F: Fn(&str) -> String + 'a> {
+  source: &'a str,
+  convert: &'a F,
+  converted: String,
+  /// The start of the source not yet copied into `converted` or `text`.
+  start: usize,
+  /// The run of text since the last markup, with code replaced by the
+  /// placeholder.
+  text: String,
+  /// The code replaced by the placeholder in `text`.
+  code: Vec<&'a str>,
+  /// Whether code is replaced by the placeholder, which it is unless the
+  /// source has the placeholder too.
+  mask: bool,
+}
+
+impl<'a, F: Fn(&str) -> String> Text<'a, F> {
+  fn new(source: &'a str, convert: &'a F) -> Self {
+    Text {
+      source,
+      convert,
+      converted: String::with_capacity(source.len()),
+      start: 0,
+      text: String::new(),
+      code: Vec::new(),
+      mask: !source.contains(PLACEHOLDER),
+    }
+  }
+
+  /// Converts the text before `start` and copies the markup from `start` to
+  /// `end`.
+  fn markup(&mut self, start: usize, end: usize) {
+    self.text(start);
+    self.converted.push_str(&self.source[start..end]);
+    self.start = end;
+  }
+
+  /// Replaces the code from `start` to `end` with the placeholder in the run
+  /// of text, or copies it as markup if code is not replaced.
+  fn code(&mut self, start: usize, end: usize) {
+    if !self.mask {
+      return self.markup(start, end);
+    }
+    self.text.push_str(&self.source[self.start..start]);
+    self.text.push(PLACEHOLDER);
+    self.code.push(&self.source[start..end]);
+    self.start = end;
+  }
+
+  /// Converts the run of text up to `end`. Whitespace around the text is
+  /// copied as it is, so conversions can not join text to markup.
+  fn text(&mut self, end: usize) {
+    self.text.push_str(&self.source[self.start..end]);
+    self.start = end;
+    let text = mem::take(&mut self.text);
+    let code = mem::take(&mut self.code);
+    let converted = convert_trimmed(&text, self.convert);
+
+    // A conversion which drops or repeats the placeholder gets the text
+    // around the code one piece at a time instead.
+    if !self.mask {
+      self.converted.push_str(&converted);
+    } else if converted.matches(PLACEHOLDER).count() == code.len() {
+      self.restore(converted.split(PLACEHOLDER).map(str::to_owned), &code);
+    } else {
+      let convert = self.convert;
+      self.restore(text.split(PLACEHOLDER).map(|piece| convert_trimmed(piece, convert)), &code);
+    }
+  }
+
+  /// Copies the pieces of text around the placeholders with the code they
+  /// replaced between them.
+  fn restore<I: Iterator<Item=String>>(&mut self, mut pieces: I, code: &[&str]) {
+    self.converted.push_str(&pieces.next().unwrap_or_default());
+    for (code, piece) in code.iter().zip(pieces) {
+      self.converted.push_str(code);
+      self.converted.push_str(&piece);
+    }
+  }
+
+  fn finish(mut self) -> String {
+    self.text(self.source.len());
+    self.converted
+  }
+}
+
+/// Converts text, copying the whitespace around it as it is.
+fn convert_trimmed<F: Fn(&str) -> String>(text: &str, convert: &F) -> String {
+  let trimmed = text.trim_start();
+  let inner = trimmed.trim_end();
+  if inner.is_empty() {
+    return text.to_owned();
+  }
+  let mut converted = String::with_capacity(text.len());
+  converted.push_str(&text[..text.len() - trimmed.len()]);
+  converted.push_str(&convert(inner));
+  converted.push_str(&trimmed[inner.len()..]);
+  converted
+}
+
+/// Converts the lines of a Markdown document selected by `select`, skipping
+/// fenced and indented code blocks.
+fn convert_markdown_lines<F, S>(markdown: &str, convert: &F, select: S) -> String
+where F: Fn(&str) -> String, S: Fn(&str) -> bool {
+  let mut converted = String::with_capacity(markdown.len());
+  // The character and length of the fence of the code block we are in.
+  let mut fence: Option<(char, usize)> = None;
+  // Whether the last line was blank or indented code, after which a line
+  // indented by four columns is indented code.
+  let mut code_may_start = true;
+
+  for line in markdown.split_inclusive('\n') {
+    let content = line.trim_end_matches(['\n', '\r']);
+    let indented = content.trim_start_matches(' ');
+    let line_fence = if content.len() - indented.len() <= 3 {
+      ['`', '~']
+      .iter()
+      .map(|&c| (c, indented.len() - indented.trim_start_matches(c).len()))
+      .find(|&(_, len)| len >= 3)
+    } else {
+      None
+    };
+
+    match (fence, line_fence) {
+      (None, Some(opening)) => fence = Some(opening),
+      (Some((c, len)), Some((line_c, line_len))) if c == line_c && line_len >= len => fence = None,
+      _ => {},
+    }
+
+    let blank = content.trim().is_empty();
+    let indented_code = fence.is_none() && line_fence.is_none() && !blank && code_may_start && indent(content) >= 4;
+    if !blank {
+      code_may_start = indented_code;
+    } else if fence.is_none() {
+      code_may_start = true;
+    }
+
+    if fence.is_some() || line_fence.is_some() || indented_code || !select(content) {
+      converted.push_str(line);
+    } else {
+      converted.push_str(&convert_markdown_inline(content, convert));
+      converted.push_str(&line[content.len()..]);
+    }
+  }
+
+  converted
+}
+
+/// Converts the text of one line of Markdown.
+fn convert_markdown_inline<F: Fn(&str) -> String>(line: &str, convert: &F) -> String {
+  let mut converted = Text::new(line, convert);
+  let prefix = block_prefix_len(line);
+  converted.markup(0, prefix);
+  let mut i = prefix;
+
+  while i < line.len() {
+    let rest = &line[i..];
+    let previous = line[..i].chars().next_back();
+    let markup = match rest.chars().next() {
+      Some('`') => {
+        let len = code_span_len(rest);
+        // An unclosed run of backticks is only punctuation.
+        if rest[..len].trim_matches('`').is_empty() {
+          Some(len)
+        } else {
+          converted.code(i, i + len);
+          i += len;
+          continue;
+        }
+      },
+      Some('<') => match tag_len(rest) {
+        Some(len) => match raw_element_len(rest, len) {
+          Some(raw) => {
+            converted.code(i, i + raw);
+            i += raw;
+            continue;
+          },
+          None => Some(len),
+        },
+        None => None,
+      },
+      Some('&') => entity_len(rest),
+      Some(']') if rest.starts_with("](") => Some(1 + closing_len(&rest[1..], '(', ')')),
+      Some(']') if rest.starts_with("][") => Some(1 + closing_len(&rest[1..], '[', ']')),
+      Some('[') | Some(']') | Some('*') | Some('~') | Some('|') => Some(1),
+      Some('!') if rest.starts_with("![") => Some(1),
+      Some('_') if !previous.is_some_and(char::is_alphanumeric) || !rest[1..].starts_with(char::is_alphanumeric) => {
+        Some(rest.len() - rest.trim_start_matches('_').len())
+      },
+      Some(_) if !previous.is_some_and(char::is_alphanumeric) => url_len(rest),
+      _ => None,
+    };
+
+    match markup {
+      Some(len) => {
+        converted.markup(i, i + len);
+        i += len;
+      },
+      None => i += rest.chars().next().map_or(1, char::len_utf8),
+    }
+  }
+
+  converted.finish()
+}
+
+/// The columns a line of Markdown is indented by, with tab stops every four
+/// columns.
+fn indent(line: &str) -> usize {
+  let mut columns = 0;
+  for c in line.chars() {
+    match c {
+      ' ' => columns += 1,
+      '\t' => columns += 4 - columns % 4,
+      _ => break,
+    }
+  }
+  columns
+}
+
+/// Checks if a line of Markdown is an ATX heading.
+fn is_heading(line: &str) -> bool {
+  let indented = line.trim_start_matches(' ');
+  let level = indented.len() - indented.trim_start_matches('#').len();
+  line.len() - indented.len() <= 3 &&
+  (1..=6).contains(&level) &&
+  (indented.len() == level || indented[level..].starts_with([' ', '\t']))
+}
+
+/// The length of the block quote, list, and heading markers at the start of
+/// a line of Markdown.
+fn block_prefix_len(line: &str) -> usize {
+  let mut i = line.len() - line.trim_start().len();
+  while line[i..].starts_with('>') {
+    i += 1;
+    i += line[i..].len() - line[i..].trim_start().len();
+  }
+  let rest = &line[i..];
+  let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+  let marker = if is_heading(rest) {
+    rest.len() - rest.trim_start_matches('#').len()
+  } else if rest.starts_with(['-', '*', '+']) {
+    1
+  } else if (1..10).contains(&digits) && rest[digits..].starts_with(['.', ')']) {
+    digits + 1
+  } else {
+    0
+  };
+  if marker == 0 || !(rest.len() == marker || rest[marker..].starts_with([' ', '\t'])) {
+    return i;
+  }
+  i += marker;
+  i += line[i..].len() - line[i..].trim_start().len();
+  // A task list item like `- [x] done`.
+  for task in &["[ ] ", "[x] ", "[X] "] {
+    if line[i..].starts_with(task) {
+      i += task.len();
+    }
+  }
+  i
+}
+
+/// The length of a code span starting with a run of backticks, or of the
+/// run alone if it is not closed.
+fn code_span_len(rest: &str) -> usize {
+  let ticks = rest.len() - rest.trim_start_matches('`').len();
+  let mut i = ticks;
+  while let Some(start) = rest[i..].find('`') {
+    let start = i + start;
+    let run = rest[start..].len() - rest[start..].trim_start_matches('`').len();
+    if run == ticks {
+      return start + run;
+    }
+    i = start + run;
+  }
+  ticks
+}
+
+/// The length of a bracketed part like a link target, including nested
+/// brackets.
+fn closing_len(rest: &str, open: char, close: char) -> usize {
+  let mut depth = 0;
+  for (i, c) in rest.char_indices() {
+    if c == open {
+      depth += 1;
+    } else if c == close {
+      depth -= 1;
+      if depth == 0 {
+        return i + 1;
+      }
+    }
+  }
+  rest.len()
+}
+
+/// The length of a URL like `https://example.com`, without trailing
+/// punctuation.
+fn url_len(rest: &str) -> Option<usize> {
+  let lower = rest.get(..8).unwrap_or(rest).to_lowercase();
+  if !["http://", "https://", "mailto:", "www."].iter().any(|scheme| lower.starts_with(scheme)) {
+    return None;
+  }
+  let url = &rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())];
+  Some(url.trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"', '\'']).len())
+}
+
+/// The length of an HTML tag, comment, or declaration, or `None` if the `<`
+/// does not start one. A tag may span lines in HTML, while Markdown is only
+/// searched for tags one line at a time.
+fn tag_len(rest: &str) -> Option<usize> {
+  let next = rest[1..].chars().next()?;
+  if !(next.is_ascii_alphabetic() || next == '/' || next == '!' || next == '?') {
+    return None;
+  }
+  let mut quote = None;
+  for (i, c) in rest.char_indices().skip(1) {
+    match (quote, c) {
+      (None, '"') | (None, '\'') => quote = Some(c),
+      (Some(q), _) if q == c => quote = None,
+      (None, '>') => return Some(i + 1),
+      (None, '<') => return None,
+      _ => {},
+    }
+  }
+  None
+}
+
+/// The length of an element whose content is not text, like `<code>`, from
+/// its start tag to the end of its end tag.
+fn raw_element_len(rest: &str, start_tag_len: usize) -> Option<usize> {
+  let name: String = rest[1..]
+  .chars()
+  .take_while(char::is_ascii_alphanumeric)
+  .collect::<String>()
+  .to_lowercase();
+  if !RAW_ELEMENTS.contains(&name.as_str()) || rest[..start_tag_len].ends_with("/>") {
+    return None;
+  }
+  let end_tag = format!("</{}", name);
+  // ASCII lowercasing keeps byte offsets, so `end` is valid in `rest` too.
+  let end = rest.to_ascii_lowercase().find(&end_tag)?;
+  Some(end + rest[end..].find('>').map_or(rest.len() - end, |i| i + 1))
+}
+
+/// Elements whose content is code or otherwise not prose.
+const RAW_ELEMENTS: &[&str] = &["code", "kbd", "pre", "samp", "script", "style", "textarea"];
+
+/// The length of an entity like `&amp;` or `&#x27;`.
+fn entity_len(rest: &str) -> Option<usize> {
+  let end = rest[1..].find(';')? + 1;
+  let name = &rest[1..end];
+  let valid = match name.strip_prefix('#') {
+    Some(number) => match number.strip_prefix(['x', 'X']) {
+      Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+      None => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+    },
+    None => !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()),
+  };
+  if valid && end <= 32 { Some(end + 1) } else { None }
+}
+
+#[cfg(test)]
+mod test {
+  use case::{to_kebab_case, to_title_case, to_upper_case};
+  use title::{titleize, TitleStyle};
+  use super::{convert_html, convert_markdown, convert_markdown_headings};
+
+  #[test]
+  fn test_markdown() {
+    let markdown = "\
+# the `snake_case` guide
+
+> quote with <span class=\"x\">html</span> and https://example.com/a_b.
+- [ ] a task with **bold** and _emphasis_
+1. visit www.example.com or ![an image](img_1.png \"title\")
+
+```rust
+let x = \"code\";
+```
+~~~
+still code
+~~~
+| a | b |
+";
+    let expected = "\
+# THE `snake_case` GUIDE
+
+> QUOTE WITH <span class=\"x\">HTML</span> AND https://example.com/a_b.
+- [ ] A TASK WITH **BOLD** AND _EMPHASIS_
+1. VISIT www.example.com OR ![AN IMAGE](img_1.png \"title\")
+
+```rust
+let x = \"code\";
+```
+~~~
+still code
+~~~
+| A | B |
+";
+    assert_eq!(convert_markdown(markdown, to_upper_case), expected);
+  }
+
+  #[test]
+  fn test_markdown_headings() {
+    let markdown = "#not a heading\n## hello_world [link](#hello_world)\r\ntext\n####### seven\n";
+    let expected = "#not a heading\n## Hello World [Link](#hello_world)\r\ntext\n####### seven\n";
+    assert_eq!(convert_markdown_headings(markdown, to_title_case), expected);
+  }
+
+  #[test]
+  fn test_markdown_code_spans() {
+    assert_eq!(convert_markdown("a ``b ` c`` d", to_upper_case), "A ``b ` c`` D");
+    assert_eq!(convert_markdown("a `unclosed b", to_upper_case), "A `UNCLOSED B");
+    assert_eq!(convert_markdown("snake_case & a < b", to_upper_case), "SNAKE_CASE & A < B");
+  }
+
+  #[test]
+  fn test_html() {
+    let html = "<p title='a > b'>hello world</p><!-- a comment --> <pre>keep  this</pre><br/>bye &#x27;now&#39;";
+    let expected = "<p title='a > b'>hello-world</p><!-- a comment --> <pre>keep  this</pre><br/>bye &#x27;now&#39;";
+    assert_eq!(convert_html(html, to_kebab_case), expected);
+    assert_eq!(convert_html("<CODE>x</CODE> y", to_upper_case), "<CODE>x</CODE> Y");
+    assert_eq!(convert_html("a <b", to_upper_case), "A <B");
+  }
+
+  #[test]
+  fn test_html_tags_spanning_lines() {
+    let html = "<a\n  href=\"/user_profile\" class=\"nav_link\">user profile</a>";
+    let expected = "<a\n  href=\"/user_profile\" class=\"nav_link\">USER PROFILE</a>";
+    assert_eq!(convert_html(html, to_upper_case), expected);
+  }
+
+  #[test]
+  fn test_markdown_code() {
+    assert_eq!(convert_markdown("use <code>snake_case</code> here", to_upper_case), "USE <code>snake_case</code> HERE");
+    assert_eq!(convert_markdown("a <pre>b c</pre> d", to_upper_case), "A <pre>b c</pre> D");
+    let markdown = "text\n\n    indented code block\n\tmore code\n\nmore text\n    lazy continuation\n";
+    let expected = "TEXT\n\n    indented code block\n\tmore code\n\nMORE TEXT\n    LAZY CONTINUATION\n";
+    assert_eq!(convert_markdown(markdown, to_upper_case), expected);
+    assert_eq!(convert_markdown("    code\n", to_upper_case), "    code\n");
+  }
+
+  #[test]
+  fn test_markdown_headings_with_code() {
+    let chicago = |text: &str| titleize(text, TitleStyle::Chicago);
+    assert_eq!(convert_markdown_headings("# a guide to `serde` in rust", chicago), "# A Guide to `serde` in Rust");
+    assert_eq!(convert_markdown_headings("## `cargo` and <code>rustc</code> for you", chicago), "## `cargo` and <code>rustc</code> for You");
+    // A conversion which drops the placeholder converts around the code.
+    let letters = |text: &str| text.chars().filter(|c| c.is_alphanumeric() || *c == ' ').collect::<String>().to_uppercase();
+    assert_eq!(convert_markdown("one `x` two", letters), "ONE `x` TWO");
+    assert_eq!(convert_markdown("one \u{fffc} `x`", to_upper_case), "ONE \u{fffc} `x`");
+  }
+
+  #[test]
+  fn test_html_non_ascii_raw_elements() {
+    assert_eq!(convert_html("<code>\u{212A}\u{212A}é</code> hi", to_upper_case), "<code>\u{212A}\u{212A}é</code> HI");
+    assert_eq!(convert_html("<PRE>İstanbul ǅ</Pre> straße", to_upper_case), "<PRE>İstanbul ǅ</Pre> STRASSE");
+  }
+}
@@ -0,0 +1,175 @@
+//! Utilities to write personal names in their conventional case, which
+//! `to_title_case` can not do: it writes “Mcdonald,” “O'neil,” and “Van Der
+//! Berg.”
+//!
+//! # Example
+//! ```rust
+//! use inflections::name::name_case;
+//!
+//! assert_eq!(name_case("RONALD MCDONALD"), "Ronald McDonald".to_owned());
+//! assert_eq!(name_case("jean-luc o'neil"), "Jean-Luc O'Neil".to_owned());
+//! assert_eq!(name_case("pieter van der berg iii"), "Pieter van der Berg III".to_owned());
+//! ```
+
+use inflector::{capitalize, spans};
+
+/// Converts a personal name into its conventional case.
+///
+/// - “Mc” and “Mac” prefixes are followed by a capital letter, except in
+///   names like “Macias” or “Mackie” where “Mac” is not a prefix.
+/// - Letters after the apostrophe of a one letter prefix, like “O'” or
+///   “D'”, and after hyphens are capitalized.
+/// - Nobiliary particles like “van,” “von,” “de,” and “le” are lower case,
+///   unless they start the name.
+/// - Roman numeral suffixes like “III” are upper case.
+/// - Initials like “J.R.R.” are upper case.
+///
+/// # Example
+/// ```rust
+/// # use inflections::name::name_case;
+/// assert_eq!(name_case("MARY MACDONALD-SMITH"), "Mary MacDonald-Smith".to_owned());
+/// assert_eq!(name_case("GEORGE MACIAS"), "George Macias".to_owned());
+/// assert_eq!(name_case("LUDWIG VON MISES"), "Ludwig von Mises".to_owned());
+/// assert_eq!(name_case("van morrison"), "Van Morrison".to_owned());
+/// assert_eq!(name_case("j.r.r. tolkien"), "J.R.R. Tolkien".to_owned());
+/// assert_eq!(name_case("martin luther king jr."), "Martin Luther King Jr.".to_owned());
+/// ```
+pub fn name_case(name: &str) -> String {
+  name_case_with(name, &[])
+}
+
+/// Converts a personal name into its conventional case consulting the
+/// `exceptions` table of names in their exact spelling before the built-in
+/// rules.
+///
+/// # Example
+/// ```rust
+/// # use inflections::name::name_case_with;
+/// let exceptions = ["Mackintosh", "DeVito", "MacIntyre"];
+/// assert_eq!(name_case_with("DANNY DEVITO", &exceptions), "Danny DeVito".to_owned());
+/// assert_eq!(name_case_with("ann mackintosh", &exceptions), "Ann Mackintosh".to_owned());
+/// assert_eq!(name_case_with("alasdair macintyre", &exceptions), "Alasdair MacIntyre".to_owned());
+/// ```
+pub fn name_case_with(name: &str, exceptions: &[&str]) -> String {
+  let mut cased = String::with_capacity(name.len());
+  let mut end = 0;
+  let mut first = true;
+
+  for (start, stop) in spans(name, char::is_whitespace) {
+    cased.push_str(&name[end..start]);
+    end = stop;
+    let word = name[start..stop].to_lowercase();
+
+    match exceptions.iter().find(|exception| exception.to_lowercase() == word) {
+      Some(exception) => cased.push_str(exception),
+      None if !first && PARTICLES.contains(&word.as_str()) => cased.push_str(&word),
+      None if !first && is_roman_numeral(&word) => cased.push_str(&word.to_uppercase()),
+      None => cased.push_str(&case_word(&word, exceptions)),
+    }
+    first = false;
+  }
+
+  cased.push_str(&name[end..]);
+  cased
+}
+
+/// Lower case nobiliary particles, which are not capitalized inside a name.
+const PARTICLES: &[&str] = &[
+  "al", "bin", "da", "dal", "de", "del", "della", "der", "des", "di", "du", "la", "le", "ten",
+  "ter", "van", "von", "zu",
+];
+
+/// Lower case names starting with “mac” where it is not a prefix.
+const NOT_MAC: &[&str] = &[
+  "macedo", "machado", "machar", "machiavelli", "machin", "machlin", "macias", "maciulis", "mackay",
+  "mackenzie", "mackey", "mackie", "mackle", "macklin", "mackmin", "macomber", "macquarie",
+];
+
+/// Cases a lower case word of a name, capitalizing every part after a
+/// hyphen or the period of an initial.
+fn case_word(word: &str, exceptions: &[&str]) -> String {
+  let mut cased = String::with_capacity(word.len());
+  let mut part_start = 0;
+  for (i, c) in word.char_indices() {
+    if c == '-' || c == '.' {
+      cased.push_str(&case_part(&word[part_start..i], exceptions));
+      cased.push(c);
+      part_start = i + c.len_utf8();
+    }
+  }
+  cased.push_str(&case_part(&word[part_start..], exceptions));
+  cased
+}
+
+/// Cases a lower case part of a name without hyphens or periods.
+fn case_part(part: &str, exceptions: &[&str]) -> String {
+  if let Some(exception) = exceptions.iter().find(|exception| exception.to_lowercase() == part) {
+    return (*exception).to_owned();
+  }
+  // A one letter prefix before an apostrophe, like “O'” in “O'Neil.”
+  if let Some(i) = part.find(['\'', '’']) {
+    let (prefix, rest) = part.split_at(i);
+    let apostrophe = rest.chars().next().map_or(0, char::len_utf8);
+    let rest = &rest[apostrophe..];
+    if prefix.chars().count() == 1 && !rest.is_empty() {
+      return format!("{}{}{}", capitalize(prefix), &part[i..i + apostrophe], case_part(rest, exceptions));
+    }
+    return capitalize(part);
+  }
+  if let Some(rest) = part.strip_prefix("mc").filter(|rest| !rest.is_empty()) {
+    return format!("Mc{}", capitalize(rest));
+  }
+  if let Some(rest) = part.strip_prefix("mac").filter(|rest| rest.chars().count() >= 3) {
+    if !NOT_MAC.contains(&part) {
+      return format!("Mac{}", capitalize(rest));
+    }
+  }
+  capitalize(part)
+}
+
+/// Checks if a lower case word is a Roman numeral from “i” to “xxxix.”
+fn is_roman_numeral(word: &str) -> bool {
+  let ones = word.trim_start_matches('x');
+  word.len() - ones.len() <= 3 &&
+  !word.is_empty() &&
+  ["", "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"].contains(&ones)
+}
+
+#[cfg(test)]
+mod test {
+  use super::name_case;
+
+  const NAMES: &[(&str, &str)] = &[
+    ("MCDONALD", "McDonald"),
+    ("mcgee", "McGee"),
+    ("MACDONALD", "MacDonald"),
+    ("MACK", "Mack"),
+    ("MACY", "Macy"),
+    ("MACHADO", "Machado"),
+    ("O'NEIL", "O'Neil"),
+    ("D’ANGELO", "D’Angelo"),
+    ("O'MCDONALD", "O'McDonald"),
+    ("JEAN-LUC PICARD", "Jean-Luc Picard"),
+    ("ANNE-MARIE SMITH-JONES", "Anne-Marie Smith-Jones"),
+    ("LEONARDO DA VINCI", "Leonardo da Vinci"),
+    ("CHARLES DE GAULLE", "Charles de Gaulle"),
+    ("DE LA CRUZ", "De la Cruz"),
+    ("JOHN SMITH III", "John Smith III"),
+    ("HENRY VIII", "Henry VIII"),
+    ("POPE JOHN XXIII", "Pope John XXIII"),
+    ("XAVIER", "Xavier"),
+    ("VI", "Vi"),
+    ("J. R. R. TOLKIEN", "J. R. R. Tolkien"),
+    ("E.E. CUMMINGS", "E.E. Cummings"),
+    ("  ada   lovelace ", "  Ada   Lovelace "),
+    ("ÉMILE ZOLA", "Émile Zola"),
+    ("", ""),
+  ];
+
+  #[test]
+  fn test_name_case() {
+    for &(name, expected) in NAMES {
+      assert_eq!(name_case(name), expected);
+    }
+  }
+}
@@ -0,0 +1,264 @@
+//! Utilities to write integers as ordinal numbers, like “21st” or
+//! “twenty-first,” which give the position of something in a sequence.
+//!
+//! English is used by default, and `ordinalize_in` uses the suffixes of other
+//! languages like French (“1er,” “2e”) or Spanish (“1.º”). Implement
+//! `OrdinalLocale` to use the suffixes of any other language.
+//!
+//! # Example
+//! ```rust
+//! use inflections::ordinal::{ordinalize, ordinalize_in, ordinal_words};
+//!
+//! assert_eq!(ordinalize(21), "21st".to_owned());
+//! assert_eq!(ordinalize(112), "112th".to_owned());
+//! assert_eq!(ordinal_words(21), "twenty-first".to_owned());
+//! assert_eq!(ordinalize_in("fr", 1), "1er".to_owned());
+//! ```
+
+use inflector::language;
+use words::{self, NumberWords};
+
+/// The suffix which turns an integer into an English ordinal number.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::ordinal;
+/// assert_eq!(ordinal(1), "st");
+/// assert_eq!(ordinal(2), "nd");
+/// assert_eq!(ordinal(3), "rd");
+/// assert_eq!(ordinal(4), "th");
+/// assert_eq!(ordinal(11), "th");
+/// assert_eq!(ordinal(1002), "nd");
+/// assert_eq!(ordinal(-1), "st");
+/// assert_eq!(ordinal(-11), "th");
+/// ```
+pub fn ordinal<N: Integer>(n: N) -> &'static str {
+  English.suffix(n.unsigned_abs())
+}
+
+/// Writes an integer as an English ordinal number.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::ordinalize;
+/// assert_eq!(ordinalize(1), "1st".to_owned());
+/// assert_eq!(ordinalize(13), "13th".to_owned());
+/// assert_eq!(ordinalize(23u8), "23rd".to_owned());
+/// assert_eq!(ordinalize(-21), "-21st".to_owned());
+/// assert_eq!(ordinalize(0usize), "0th".to_owned());
+/// ```
+pub fn ordinalize<N: Integer>(n: N) -> String {
+  ordinalize_with(n, &English)
+}
+
+/// Writes an integer as an ordinal number using the suffixes of a locale,
+/// such as `"fr"` or `"es-MX"`. Locales without built-in suffixes use the
+/// English suffixes.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::ordinalize_in;
+/// assert_eq!(ordinalize_in("fr", 1), "1er".to_owned());
+/// assert_eq!(ordinalize_in("fr", 2), "2e".to_owned());
+/// assert_eq!(ordinalize_in("es-MX", 1), "1.º".to_owned());
+/// assert_eq!(ordinalize_in("de", 3), "3.".to_owned());
+/// assert_eq!(ordinalize_in("en-GB", 3), "3rd".to_owned());
+/// ```
+pub fn ordinalize_in<N: Integer>(locale: &str, n: N) -> String {
+  ordinalize_with(n, for_locale(locale).unwrap_or(&English))
+}
+
+/// Writes an integer as an ordinal number using the suffixes of an
+/// `OrdinalLocale`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::{ordinalize_with, OrdinalLocale};
+/// struct Italian;
+///
+/// impl OrdinalLocale for Italian {
+///   fn suffix(&self, _: u128) -> &str { "º" }
+/// }
+///
+/// assert_eq!(ordinalize_with(4, &Italian), "4º".to_owned());
+/// ```
+pub fn ordinalize_with<N: Integer>(n: N, locale: &dyn OrdinalLocale) -> String {
+  let magnitude = n.unsigned_abs();
+  let sign = if n.is_negative() { "-" } else { "" };
+  format!("{}{}{}", sign, magnitude, locale.suffix(magnitude))
+}
+
+/// Writes an integer as an English ordinal number in words.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::ordinal_words;
+/// assert_eq!(ordinal_words(0), "zeroth".to_owned());
+/// assert_eq!(ordinal_words(1), "first".to_owned());
+/// assert_eq!(ordinal_words(12), "twelfth".to_owned());
+/// assert_eq!(ordinal_words(21), "twenty-first".to_owned());
+/// assert_eq!(ordinal_words(40), "fortieth".to_owned());
+/// assert_eq!(ordinal_words(100), "one hundredth".to_owned());
+/// assert_eq!(ordinal_words(1_000_003), "one million third".to_owned());
+/// assert_eq!(ordinal_words(-2), "minus second".to_owned());
+/// ```
+pub fn ordinal_words<N: Integer>(n: N) -> String {
+  let cardinal = words::English::new().integer(&n.unsigned_abs().to_string());
+  let start = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+  let last = &cardinal[start..];
+  let ordinal = match last {
+    "one" => "first".to_owned(),
+    "two" => "second".to_owned(),
+    "three" => "third".to_owned(),
+    "five" => "fifth".to_owned(),
+    "eight" => "eighth".to_owned(),
+    "nine" => "ninth".to_owned(),
+    "twelve" => "twelfth".to_owned(),
+    _ if last.ends_with('y') => format!("{}ieth", &last[..last.len() - 1]),
+    _ => format!("{}th", last),
+  };
+  let sign = if n.is_negative() { "minus " } else { "" };
+  format!("{}{}{}", sign, &cardinal[..start], ordinal)
+}
+
+/// The ordinal suffixes of a language.
+pub trait OrdinalLocale {
+  /// The suffix written after the magnitude of an integer.
+  fn suffix(&self, n: u128) -> &str;
+}
+
+/// English ordinal suffixes: “1st,” “2nd,” “3rd,” “4th,” “11th.”
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+impl OrdinalLocale for English {
+  fn suffix(&self, n: u128) -> &str {
+    match (n % 10, n % 100) {
+      (_, 11..=13) => "th",
+      (1, _) => "st",
+      (2, _) => "nd",
+      (3, _) => "rd",
+      _ => "th",
+    }
+  }
+}
+
+/// French ordinal suffixes: “1er,” “2e.”
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct French;
+
+impl OrdinalLocale for French {
+  fn suffix(&self, n: u128) -> &str {
+    if n == 1 { "er" } else { "e" }
+  }
+}
+
+/// Spanish masculine ordinal suffixes: “1.º,” “2.º.”
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Spanish;
+
+impl OrdinalLocale for Spanish {
+  fn suffix(&self, _: u128) -> &str {
+    ".º"
+  }
+}
+
+/// Ordinal suffixes of languages which write a period after the number, like
+/// German and Danish: “1.,” “2.”
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Period;
+
+impl OrdinalLocale for Period {
+  fn suffix(&self, _: u128) -> &str {
+    "."
+  }
+}
+
+/// The built-in ordinal suffixes of a locale, like `"fr"` or `"es-MX"`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::ordinal::for_locale;
+/// assert_eq!(for_locale("fr_CA").unwrap().suffix(1), "er");
+/// assert!(for_locale("tlh").is_none());
+/// ```
+pub fn for_locale(locale: &str) -> Option<&'static dyn OrdinalLocale> {
+  match language(locale).as_str() {
+    "en" => Some(&English),
+    "fr" => Some(&French),
+    "es" => Some(&Spanish),
+    "de" | "da" | "nb" | "no" | "fi" | "cs" | "sk" | "pl" | "hu" | "tr" => Some(&Period),
+    _ => None,
+  }
+}
+
+/// An integer type which may be written as an ordinal number.
+pub trait Integer: Copy {
+  /// The absolute value of the integer.
+  fn unsigned_abs(self) -> u128;
+  /// Whether the integer is less than zero.
+  fn is_negative(self) -> bool;
+}
+
+macro_rules! impl_signed {
+  ($($t:ty),*) => {$(
+    impl Integer for $t {
+      #[inline] fn unsigned_abs(self) -> u128 { self.unsigned_abs() as u128 }
+      #[inline] fn is_negative(self) -> bool { self < 0 }
+    }
+  )*};
+}
+
+macro_rules! impl_unsigned {
+  ($($t:ty),*) => {$(
+    impl Integer for $t {
+      #[inline] fn unsigned_abs(self) -> u128 { self as u128 }
+      #[inline] fn is_negative(self) -> bool { false }
+    }
+  )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+  use super::{ordinal_words, ordinalize, ordinalize_in};
+
+  #[test]
+  fn test_ordinalize() {
+    let expected = [
+      (0, "0th"), (1, "1st"), (2, "2nd"), (3, "3rd"), (4, "4th"), (10, "10th"),
+      (11, "11th"), (12, "12th"), (13, "13th"), (21, "21st"), (22, "22nd"), (23, "23rd"),
+      (100, "100th"), (101, "101st"), (111, "111th"), (112, "112th"), (1011, "1011th"),
+      (-1, "-1st"), (-12, "-12th"), (-103, "-103rd"),
+    ];
+    for &(n, ordinal) in &expected {
+      assert_eq!(ordinalize(n), ordinal);
+    }
+    assert_eq!(ordinalize(i128::MIN), "-170141183460469231731687303715884105728th");
+    assert_eq!(ordinalize(u128::MAX), "340282366920938463463374607431768211455th");
+  }
+
+  #[test]
+  fn test_ordinal_words() {
+    let expected = [
+      (3, "third"), (5, "fifth"), (8, "eighth"), (9, "ninth"), (11, "eleventh"),
+      (20, "twentieth"), (99, "ninety-ninth"), (101, "one hundred first"),
+      (1000, "one thousandth"), (2_000_000, "two millionth"),
+      (1234, "one thousand two hundred thirty-fourth"),
+    ];
+    for &(n, words) in &expected {
+      assert_eq!(ordinal_words(n), words);
+    }
+  }
+
+  #[test]
+  fn test_locales() {
+    assert_eq!(ordinalize_in("fr", -1), "-1er");
+    assert_eq!(ordinalize_in("FR", 21), "21e");
+    assert_eq!(ordinalize_in("es", 21), "21.º");
+    assert_eq!(ordinalize_in("da-DK", 7), "7.");
+    assert_eq!(ordinalize_in("tlh", 2), "2nd");
+  }
+}
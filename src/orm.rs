@@ -0,0 +1,67 @@
+//! Utilities to name the tables, models, and foreign keys of a database
+//! schema like Active Record does: tables are plural snake_case, models are
+//! singular PascalCase, and foreign keys end with `_id`.
+//!
+//! # Example
+//! ```rust
+//! use inflections::orm::{classify, foreign_key, join_table, tableize};
+//!
+//! assert_eq!(tableize("RawScaledScorer"), "raw_scaled_scorers".to_owned());
+//! assert_eq!(classify("egg_and_hams"), "EggAndHam".to_owned());
+//! assert_eq!(foreign_key("Message"), "message_id".to_owned());
+//! assert_eq!(join_table("Product", "Category"), "categories_products".to_owned());
+//! ```
+
+use inflector::default_inflector;
+
+/// Converts a model name into the name of its table.
+///
+/// # Example
+/// ```rust
+/// # use inflections::orm::tableize;
+/// assert_eq!(tableize("RawScaledScorer"), "raw_scaled_scorers".to_owned());
+/// assert_eq!(tableize("egg_and_ham"), "egg_and_hams".to_owned());
+/// assert_eq!(tableize("person"), "people".to_owned());
+/// ```
+pub fn tableize(model: &str) -> String {
+  default_inflector().tableize(model)
+}
+
+/// Converts a table name into the name of its model.
+///
+/// # Example
+/// ```rust
+/// # use inflections::orm::classify;
+/// assert_eq!(classify("egg_and_hams"), "EggAndHam".to_owned());
+/// assert_eq!(classify("posts"), "Post".to_owned());
+/// assert_eq!(classify("public.people"), "Person".to_owned());
+/// ```
+pub fn classify(table: &str) -> String {
+  default_inflector().classify(table)
+}
+
+/// Converts a model name into the name of a foreign key column referencing
+/// its table.
+///
+/// # Example
+/// ```rust
+/// # use inflections::orm::foreign_key;
+/// assert_eq!(foreign_key("Message"), "message_id".to_owned());
+/// assert_eq!(foreign_key("AdminPost"), "admin_post_id".to_owned());
+/// ```
+pub fn foreign_key(model: &str) -> String {
+  default_inflector().foreign_key(model)
+}
+
+/// Derives the name of the join table of a many-to-many association between
+/// two models.
+///
+/// # Example
+/// ```rust
+/// # use inflections::orm::join_table;
+/// assert_eq!(join_table("Product", "Category"), "categories_products".to_owned());
+/// assert_eq!(join_table("Developer", "Project"), "developers_projects".to_owned());
+/// ```
+pub fn join_table(model: &str, other: &str) -> String {
+  default_inflector().join_table(model, other)
+}
@@ -0,0 +1,193 @@
+//! Utilities for qualified paths like `crate::http::RequestBuilder`,
+//! `Admin::UserProfile`, or `admin/user_profile.rb`. Each segment of a path is
+//! converted on its own, and namespace separators are mapped between `::`,
+//! `/`, `.`, and `\`. A `.` only separates segments in paths without any other
+//! separator, like `com.example.MyApp`, so file extensions are kept.
+//!
+//! # Example
+//! ```rust
+//! use inflections::case::{to_pascal_case, to_snake_case};
+//! use inflections::path::{convert_path, demodulize, deconstantize, Separator};
+//!
+//! assert_eq!(convert_path("Admin::UserProfile", Separator::Slash, to_snake_case), "admin/user_profile".to_owned());
+//! assert_eq!(convert_path("admin/user_profile", Separator::DoubleColon, to_pascal_case), "Admin::UserProfile".to_owned());
+//! assert_eq!(demodulize("crate::http::RequestBuilder"), "RequestBuilder");
+//! assert_eq!(deconstantize("crate::http::RequestBuilder"), "crate::http");
+//! ```
+
+/// A separator between the segments of a path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Separator {
+  /// `::`, as in Rust paths and Ruby constants.
+  DoubleColon,
+  /// `/`, as in Unix file paths and URLs.
+  Slash,
+  /// `.`, as in Java packages and Python modules.
+  Dot,
+  /// `\`, as in Windows file paths and PHP namespaces.
+  Backslash,
+}
+
+impl Separator {
+  /// The separator as it is written in a path.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::path::Separator;
+  /// assert_eq!(Separator::DoubleColon.as_str(), "::");
+  /// assert_eq!(Separator::Backslash.as_str(), "\\");
+  /// ```
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Separator::DoubleColon => "::",
+      Separator::Slash => "/",
+      Separator::Dot => ".",
+      Separator::Backslash => "\\",
+    }
+  }
+}
+
+/// Converts every segment of a path with `convert` and joins the segments
+/// with `separator`. The segments may be separated by any of `::`, `/`, or
+/// `\`, or else by `.`. The extension of the last segment of a file path, like
+/// the `.rs` of `src/main.rs`, is kept as it is. Empty segments, like the one
+/// before the leading `::` of `::Admin::User`, are kept.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_kebab_case, to_pascal_case, to_snake_case};
+/// # use inflections::path::{convert_path, Separator};
+/// assert_eq!(convert_path("crate::http::RequestBuilder", Separator::Slash, to_snake_case), "crate/http/request_builder".to_owned());
+/// assert_eq!(convert_path("active_model/errors", Separator::DoubleColon, to_pascal_case), "ActiveModel::Errors".to_owned());
+/// assert_eq!(convert_path("Com.Example.MyApp", Separator::Dot, to_snake_case), "com.example.my_app".to_owned());
+/// assert_eq!(convert_path("App\\Http\\UserController", Separator::Slash, to_kebab_case), "app/http/user-controller".to_owned());
+/// assert_eq!(convert_path("::Admin::User", Separator::Slash, to_snake_case), "/admin/user".to_owned());
+/// assert_eq!(convert_path("src/http/request_builder.rs", Separator::DoubleColon, to_pascal_case), "Src::Http::RequestBuilder.rs".to_owned());
+/// ```
+pub fn convert_path<F: Fn(&str) -> String>(path: &str, separator: Separator, convert: F) -> String {
+  let mut converted = String::with_capacity(path.len());
+  let mut end = 0;
+  for (start, stop) in separators(path) {
+    converted.push_str(&convert_segment(&path[end..start], &convert));
+    converted.push_str(separator.as_str());
+    end = stop;
+  }
+  // The extension of a file starts at a `.` after its first character.
+  let last = &path[end..];
+  let (stem, extension) = match last.char_indices().skip(1).find(|&(_, c)| c == '.') {
+    Some((i, _)) if !dots(path) => last.split_at(i),
+    _ => (last, ""),
+  };
+  converted.push_str(&convert_segment(stem, &convert));
+  converted.push_str(extension);
+  converted
+}
+
+/// Gets the last segment of a path.
+///
+/// # Example
+/// ```rust
+/// # use inflections::path::demodulize;
+/// assert_eq!(demodulize("Admin::UserProfile"), "UserProfile");
+/// assert_eq!(demodulize("admin/user_profile"), "user_profile");
+/// assert_eq!(demodulize("app/models/user.rb"), "user.rb");
+/// assert_eq!(demodulize("UserProfile"), "UserProfile");
+/// assert_eq!(demodulize(""), "");
+/// ```
+pub fn demodulize(path: &str) -> &str {
+  match separators(path).last() {
+    Some(&(_, stop)) => &path[stop..],
+    None => path,
+  }
+}
+
+/// Gets every segment of a path except for the last one, with the
+/// separators they were written with.
+///
+/// # Example
+/// ```rust
+/// # use inflections::path::deconstantize;
+/// assert_eq!(deconstantize("Net::HTTP::Get"), "Net::HTTP");
+/// assert_eq!(deconstantize("::Net::HTTP"), "::Net");
+/// assert_eq!(deconstantize("app/models/user.rb"), "app/models");
+/// assert_eq!(deconstantize("com.example.MyApp"), "com.example");
+/// assert_eq!(deconstantize("String"), "");
+/// ```
+pub fn deconstantize(path: &str) -> &str {
+  match separators(path).last() {
+    Some(&(start, _)) => &path[..start],
+    None => "",
+  }
+}
+
+/// Converts a segment, leaving it empty if it is empty.
+#[inline]
+fn convert_segment<F: Fn(&str) -> String>(segment: &str, convert: &F) -> String {
+  if segment.is_empty() { String::new() } else { convert(segment) }
+}
+
+/// Checks if `.` separates the segments of a path, which it only does when
+/// the path has no other separator.
+#[inline]
+fn dots(path: &str) -> bool {
+  !path.contains("::") && !path.contains(['/', '\\'])
+}
+
+/// Finds the start and end of every separator in a path. A single colon is
+/// not a separator.
+fn separators(path: &str) -> Vec<(usize, usize)> {
+  let dots = dots(path);
+  let mut separators = Vec::new();
+  let mut i = 0;
+  while i < path.len() {
+    let rest = &path[i..];
+    let len = if rest.starts_with("::") {
+      2
+    } else if rest.starts_with(['/', '\\']) || dots && rest.starts_with('.') {
+      1
+    } else {
+      0
+    };
+    if len > 0 {
+      separators.push((i, i + len));
+      i += len;
+    } else {
+      i += rest.chars().next().map_or(1, char::len_utf8);
+    }
+  }
+  separators
+}
+
+#[cfg(test)]
+mod test {
+  use case::{to_pascal_case, to_snake_case};
+  use super::{convert_path, deconstantize, demodulize, Separator};
+
+  #[test]
+  fn test_convert_path() {
+    assert_eq!(convert_path("", Separator::Slash, to_snake_case), "");
+    assert_eq!(convert_path("HTTPServer", Separator::Slash, to_snake_case), "httpserver");
+    assert_eq!(convert_path("a::b/c\\d", Separator::DoubleColon, to_pascal_case), "A::B::C::D");
+    assert_eq!(convert_path("a.b::c_d", Separator::Slash, to_snake_case), "a.b/c_d");
+    assert_eq!(convert_path("admin/", Separator::DoubleColon, to_pascal_case), "Admin::");
+    assert_eq!(convert_path("std:io", Separator::Slash, to_snake_case), "std:io");
+  }
+
+  #[test]
+  fn test_segments() {
+    assert_eq!(demodulize("::"), "");
+    assert_eq!(deconstantize("::"), "");
+    assert_eq!(demodulize("a::b:c"), "b:c");
+    assert_eq!(deconstantize("Ä::Ö::Ü"), "Ä::Ö");
+  }
+
+  #[test]
+  fn test_extensions() {
+    assert_eq!(convert_path("app/models/UserProfile.rb", Separator::Slash, to_snake_case), "app/models/user_profile.rb");
+    assert_eq!(convert_path("Lib\\.HiddenFile", Separator::Slash, to_snake_case), "lib/.hidden_file");
+    assert_eq!(convert_path("v1.2/read_me.tar.gz", Separator::Slash, to_pascal_case), "V1.2/ReadMe.tar.gz");
+    assert_eq!(convert_path("Ünïcode/Ärger.TXT", Separator::Slash, to_snake_case), "ünïcode/ärger.TXT");
+    assert_eq!(demodulize("src/http/request_builder.rs"), "request_builder.rs");
+    assert_eq!(deconstantize("src/http/request_builder.rs"), "src/http");
+  }
+}
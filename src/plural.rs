@@ -0,0 +1,176 @@
+//! Utilities to change the grammatical number of English nouns, turning a
+//! singular noun into a plural noun and back again.
+//!
+//! Compounds like “mother-in-law” or “attorney general” are inflected on
+//! their head word, while identifiers like `sign_in_attempt` are inflected on
+//! their last word.
+//!
+//! # Example
+//! ```rust
+//! use inflections::plural::pluralize;
+//!
+//! assert_eq!(pluralize("blog post"), "blog posts".to_owned());
+//! assert_eq!(pluralize("mother-in-law"), "mothers-in-law".to_owned());
+//! ```
+
+use inflector::{default_inflector, Number};
+
+/// Converts a singular noun into a plural noun.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural::pluralize;
+/// assert_eq!(pluralize("post"), "posts".to_owned());
+/// assert_eq!(pluralize("octopus"), "octopi".to_owned());
+/// assert_eq!(pluralize("person"), "people".to_owned());
+/// assert_eq!(pluralize("sheep"), "sheep".to_owned());
+/// assert_eq!(pluralize("blog_post"), "blog_posts".to_owned());
+/// assert_eq!(pluralize("mother-in-law"), "mothers-in-law".to_owned());
+/// assert_eq!(pluralize("editor in chief"), "editors in chief".to_owned());
+/// assert_eq!(pluralize("attorney general"), "attorneys general".to_owned());
+/// assert_eq!(pluralize("court-martial"), "courts-martial".to_owned());
+/// assert_eq!(pluralize("passerby"), "passersby".to_owned());
+/// ```
+pub fn pluralize(string: &str) -> String {
+  pluralize_with(string, &[])
+}
+
+/// Converts a singular noun into a plural noun consulting the `exceptions`
+/// table of `(singular, plural)` pairs before the built-in compound noun
+/// exceptions.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural::pluralize_with;
+/// let exceptions = [("attorney general", "attorney generals")];
+/// assert_eq!(pluralize_with("attorney general", &exceptions), "attorney generals".to_owned());
+/// assert_eq!(pluralize_with("Town Crier", &[("town crier", "town criers")]), "Town Criers".to_owned());
+/// ```
+pub fn pluralize_with(string: &str, exceptions: &[(&str, &str)]) -> String {
+  default_inflector().inflect(string, exceptions, Number::Plural)
+}
+
+/// Converts a plural noun into a singular noun.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural::singularize;
+/// assert_eq!(singularize("posts"), "post".to_owned());
+/// assert_eq!(singularize("octopi"), "octopus".to_owned());
+/// assert_eq!(singularize("people"), "person".to_owned());
+/// assert_eq!(singularize("sheep"), "sheep".to_owned());
+/// assert_eq!(singularize("blog_posts"), "blog_post".to_owned());
+/// assert_eq!(singularize("mothers-in-law"), "mother-in-law".to_owned());
+/// assert_eq!(singularize("attorneys general"), "attorney general".to_owned());
+/// assert_eq!(singularize("courts-martial"), "court-martial".to_owned());
+/// assert_eq!(singularize("passersby"), "passerby".to_owned());
+/// ```
+pub fn singularize(string: &str) -> String {
+  singularize_with(string, &[])
+}
+
+/// Converts a plural noun into a singular noun consulting the `exceptions`
+/// table of `(singular, plural)` pairs before the built-in compound noun
+/// exceptions.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural::singularize_with;
+/// let exceptions = [("attorney general", "attorney generals")];
+/// assert_eq!(singularize_with("attorney generals", &exceptions), "attorney general".to_owned());
+/// ```
+pub fn singularize_with(string: &str, exceptions: &[(&str, &str)]) -> String {
+  default_inflector().inflect(string, exceptions, Number::Singular)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{pluralize, singularize};
+
+  const NOUNS: &[(&str, &str)] = &[
+    ("search", "searches"),
+    ("switch", "switches"),
+    ("fix", "fixes"),
+    ("box", "boxes"),
+    ("process", "processes"),
+    ("address", "addresses"),
+    ("case", "cases"),
+    ("stack", "stacks"),
+    ("wish", "wishes"),
+    ("category", "categories"),
+    ("query", "queries"),
+    ("ability", "abilities"),
+    ("agency", "agencies"),
+    ("movie", "movies"),
+    ("archive", "archives"),
+    ("index", "indices"),
+    ("wife", "wives"),
+    ("half", "halves"),
+    ("basis", "bases"),
+    ("diagnosis", "diagnoses"),
+    ("datum", "data"),
+    ("medium", "media"),
+    ("analysis", "analyses"),
+    ("node_child", "node_children"),
+    ("experience", "experiences"),
+    ("day", "days"),
+    ("comment", "comments"),
+    ("foobar", "foobars"),
+    ("newsletter", "newsletters"),
+    ("news", "news"),
+    ("series", "series"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("house", "houses"),
+    ("ox", "oxen"),
+    ("vertex", "vertices"),
+    ("matrix", "matrices"),
+    ("quiz", "quizzes"),
+    ("bus", "buses"),
+    ("alias", "aliases"),
+    ("status", "statuses"),
+    ("virus", "viri"),
+    ("axis", "axes"),
+    ("testis", "testes"),
+    ("crisis", "crises"),
+    ("shoe", "shoes"),
+    ("tomato", "tomatoes"),
+    ("database", "databases"),
+    ("Person", "People"),
+    ("POST", "POSTS"),
+    ("man-of-war", "men-of-war"),
+    ("Mother-In-Law", "Mothers-In-Law"),
+    ("commander in chief", "commanders in chief"),
+    ("attorney-at-law", "attorneys-at-law"),
+    ("Attorney General", "Attorneys General"),
+    ("deputy attorney general", "deputy attorneys general"),
+    ("COURT_MARTIAL", "COURTS_MARTIAL"),
+    ("passer-by", "passers-by"),
+    ("runner-up", "runners-up"),
+    ("sign_in_attempt", "sign_in_attempts"),
+    ("check_in_date", "check_in_dates"),
+    ("Point Of Sale", "Points Of Sale"),
+  ];
+
+  #[test]
+  fn test_pluralize() {
+    for &(singular, plural) in NOUNS {
+      assert_eq!(pluralize(singular), plural);
+      assert_eq!(pluralize(plural), plural);
+    }
+  }
+
+  #[test]
+  fn test_singularize() {
+    for &(singular, plural) in NOUNS {
+      assert_eq!(singularize(plural), singular);
+      assert_eq!(singularize(singular), singular);
+    }
+  }
+
+  #[test]
+  fn test_empty() {
+    assert_eq!(pluralize(""), "");
+    assert_eq!(singularize(" - "), " - ");
+  }
+}
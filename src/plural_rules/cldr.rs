@@ -0,0 +1,303 @@
+//! The embedded CLDR cardinal plural rules and loaders for CLDR data files.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use inflector::language;
+use super::{PluralCategory, PluralRules, PluralRulesError};
+use super::PluralCategory::*;
+
+/// The “many” rule for languages which use a separate form for millions, like
+/// “un million de fichiers” in French.
+const MILLIONS: &str = "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5";
+
+/// The rules of Russian and Ukrainian, which only count integers. Belarusian
+/// counts decimal numbers like “2.0” as well, so it has rules of its own.
+const EAST_SLAVIC: &[(PluralCategory, &str)] = &[
+  (One, "v = 0 and i % 10 = 1 and i % 100 != 11"),
+  (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+  (Many, "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"),
+];
+
+/// The rules of the South Slavic languages which count fraction digits.
+const SOUTH_SLAVIC: &[(PluralCategory, &str)] = &[
+  (One, "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11"),
+  (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14"),
+];
+
+/// The CLDR cardinal plural rules of common locales, keyed by space separated
+/// lowercase locale tags. Locales without a `one` category use only `Other`.
+const LOCALES: &[(&str, &[(PluralCategory, &str)])] = &[
+  ("ja zh ko th vi id ms", &[]),
+  ("en de nl sv fi et", &[(One, "i = 1 and v = 0")]),
+  ("tr el hu bg nb no", &[(One, "n = 1")]),
+  ("da", &[(One, "n = 1 or t != 0 and i = 0,1")]),
+  ("is", &[(One, "t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11")]),
+  ("hi fa", &[(One, "i = 0 or n = 1")]),
+  ("es", &[(One, "n = 1"), (Many, MILLIONS)]),
+  ("it pt-pt", &[(One, "i = 1 and v = 0"), (Many, MILLIONS)]),
+  ("fr", &[(One, "i = 0,1"), (Many, MILLIONS)]),
+  ("pt", &[(One, "i = 0..1"), (Many, MILLIONS)]),
+  ("ru uk", EAST_SLAVIC),
+  ("be", &[
+    (One, "n % 10 = 1 and n % 100 != 11"),
+    (Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+    (Many, "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14"),
+  ]),
+  ("hr sr bs", SOUTH_SLAVIC),
+  ("pl", &[
+    (One, "i = 1 and v = 0"),
+    (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+    (Many, "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14"),
+  ]),
+  ("cs sk", &[(One, "i = 1 and v = 0"), (Few, "i = 2..4 and v = 0"), (Many, "v != 0")]),
+  ("sl", &[
+    (One, "v = 0 and i % 100 = 1"),
+    (Two, "v = 0 and i % 100 = 2"),
+    (Few, "v = 0 and i % 100 = 3..4 or v != 0"),
+  ]),
+  ("lt", &[
+    (One, "n % 10 = 1 and n % 100 != 11..19"),
+    (Few, "n % 10 = 2..9 and n % 100 != 11..19"),
+    (Many, "f != 0"),
+  ]),
+  ("lv", &[
+    (Zero, "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19"),
+    (One, "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1"),
+  ]),
+  ("ro", &[(One, "i = 1 and v = 0"), (Few, "v != 0 or n = 0 or n != 1 and n % 100 = 1..19")]),
+  ("he", &[(One, "i = 1 and v = 0 or i = 0 and v != 0"), (Two, "i = 2 and v = 0")]),
+  ("ar", &[
+    (Zero, "n = 0"),
+    (One, "n = 1"),
+    (Two, "n = 2"),
+    (Few, "n % 100 = 3..10"),
+    (Many, "n % 100 = 11..99"),
+  ]),
+  ("ga", &[(One, "n = 1"), (Two, "n = 2"), (Few, "n = 3..6"), (Many, "n = 7..10")]),
+  ("cy", &[(Zero, "n = 0"), (One, "n = 1"), (Two, "n = 2"), (Few, "n = 3"), (Many, "n = 6")]),
+];
+
+/// The embedded rules of a locale, falling back to the rules of its language.
+pub fn embedded(locale: &str) -> Option<&'static PluralRules> {
+  static RULES: OnceLock<HashMap<String, PluralRules>> = OnceLock::new();
+  let rules = RULES.get_or_init(|| {
+    let mut rules = HashMap::new();
+    for &(locales, categories) in LOCALES {
+      let parsed = PluralRules::parse(categories).expect("embedded plural rules are valid");
+      for locale in locales.split(' ') {
+        rules.insert(locale.to_owned(), parsed.clone());
+      }
+    }
+    rules
+  });
+
+  let locale = normalize(locale);
+  rules
+  .get(&locale)
+  .or_else(|| rules.get(&language(&locale)))
+}
+
+/// Lowercases a locale tag and uses `-` to separate its subtags.
+fn normalize(locale: &str) -> String {
+  locale.trim().replace('_', "-").to_lowercase()
+}
+
+/// Loads plural rules from the source of a CLDR `plurals.xml` or
+/// `ordinals.xml` file, keyed by lowercase locale tags like `"pt-pt"`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural_rules::{load_cldr_xml, PluralCategory};
+/// let rules = load_cldr_xml(r#"
+///   <plurals type="cardinal">
+///     <!-- Two locales which share rules. -->
+///     <pluralRules locales="en nl">
+///       <pluralRule count="one">i = 1 and v = 0 @integer 1</pluralRule>
+///       <pluralRule count="other"> @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, …</pluralRule>
+///     </pluralRules>
+///   </plurals>
+/// "#).unwrap();
+///
+/// assert_eq!(rules["nl"].category(1), PluralCategory::One);
+/// assert_eq!(rules["nl"].category(2), PluralCategory::Other);
+/// ```
+///
+/// # Errors
+/// Returns `PluralRulesError::InvalidData` if the file is malformed, or
+/// another error if it contains an invalid rule.
+pub fn load_cldr_xml(source: &str) -> Result<HashMap<String, PluralRules>, PluralRulesError> {
+  let source = strip_comments(source)?;
+  let mut loaded = HashMap::new();
+  let mut rest = source.as_str();
+
+  while let Some(start) = rest.find("<pluralRules") {
+    let block = &rest[start..];
+    let end = block
+    .find("</pluralRules>")
+    .ok_or_else(|| invalid_data("unclosed `pluralRules` element"))?;
+    let (tag, mut body) = split_tag(&block[..end])?;
+    let locales = attribute(tag, "locales").ok_or_else(|| invalid_data("`pluralRules` has no `locales`"))?;
+
+    let mut rules = Vec::new();
+    while let Some(start) = body.find("<pluralRule") {
+      let element = &body[start..];
+      let end = element
+      .find("</pluralRule>")
+      .ok_or_else(|| invalid_data("unclosed `pluralRule` element"))?;
+      let (tag, text) = split_tag(&element[..end])?;
+      let count = attribute(tag, "count").ok_or_else(|| invalid_data("`pluralRule` has no `count`"))?;
+      rules.push((count.parse::<PluralCategory>()?, decode_entities(text)));
+      body = &element[end..];
+    }
+
+    let rules: Vec<_> = rules.iter().map(|(category, rule)| (*category, rule.as_str())).collect();
+    let rules = PluralRules::parse(&rules)?;
+    for locale in locales.split_whitespace() {
+      loaded.insert(normalize(locale), rules.clone());
+    }
+    rest = &block[end..];
+  }
+
+  Ok(loaded)
+}
+
+/// Loads plural rules from the source of a CLDR JSON `plurals.json` or
+/// `ordinals.json` file, keyed by lowercase locale tags like `"pt-pt"`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural_rules::{load_cldr_json, PluralCategory};
+/// let rules = load_cldr_json(r#"{
+///   "supplemental": {
+///     "plurals-type-cardinal": {
+///       "pt-PT": {
+///         "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+///         "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, …"
+///       }
+///     }
+///   }
+/// }"#).unwrap();
+///
+/// assert_eq!(rules["pt-pt"].category(0), PluralCategory::Other);
+/// ```
+///
+/// # Errors
+/// Returns `PluralRulesError::InvalidData` if the file is malformed, or
+/// another error if it contains an invalid rule.
+#[cfg(feature = "json")]
+pub fn load_cldr_json(source: &str) -> Result<HashMap<String, PluralRules>, PluralRulesError> {
+  use serde_json::Value;
+
+  let document: Value = serde_json::from_str(source).map_err(|error| invalid_data(&error.to_string()))?;
+  let locales = document
+  .get("supplemental")
+  .and_then(Value::as_object)
+  .and_then(|supplemental| {
+    supplemental
+    .iter()
+    .find(|(key, _)| key.starts_with("plurals-type-"))
+    .and_then(|(_, locales)| locales.as_object())
+  })
+  .ok_or_else(|| invalid_data("missing `supplemental.plurals-type-*` object"))?;
+
+  let mut loaded = HashMap::new();
+  for (locale, categories) in locales {
+    let categories = categories
+    .as_object()
+    .ok_or_else(|| invalid_data(&format!("rules of `{}` are not an object", locale)))?;
+    let mut rules = Vec::new();
+    for (key, rule) in categories {
+      let category = key
+      .strip_prefix("pluralRule-count-")
+      .ok_or_else(|| invalid_data(&format!("unexpected key `{}`", key)))?;
+      let rule = rule
+      .as_str()
+      .ok_or_else(|| invalid_data(&format!("rule `{}` of `{}` is not a string", key, locale)))?;
+      rules.push((category.parse::<PluralCategory>()?, rule));
+    }
+    loaded.insert(normalize(locale), PluralRules::parse(&rules)?);
+  }
+
+  Ok(loaded)
+}
+
+/// Removes `<!-- -->` comments from XML source.
+fn strip_comments(source: &str) -> Result<String, PluralRulesError> {
+  let mut stripped = String::with_capacity(source.len());
+  let mut rest = source;
+  while let Some(start) = rest.find("<!--") {
+    stripped.push_str(&rest[..start]);
+    let end = rest[start..].find("-->").ok_or_else(|| invalid_data("unclosed comment"))?;
+    rest = &rest[start + end + 3..];
+  }
+  stripped.push_str(rest);
+  Ok(stripped)
+}
+
+/// Splits an element without its closing tag into its start tag and its
+/// content.
+fn split_tag(element: &str) -> Result<(&str, &str), PluralRulesError> {
+  let end = element.find('>').ok_or_else(|| invalid_data("unclosed tag"))?;
+  Ok((&element[..end], &element[end + 1..]))
+}
+
+/// The value of an attribute in a start tag.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+  ['"', '\''].iter().find_map(|&quote| {
+    let prefix = format!(" {}={}", name, quote);
+    let start = tag.find(&prefix)? + prefix.len();
+    let end = tag[start..].find(quote)?;
+    Some(&tag[start..start + end])
+  })
+}
+
+/// Decodes the predefined XML entities.
+fn decode_entities(text: &str) -> String {
+  text
+  .replace("&lt;", "<")
+  .replace("&gt;", ">")
+  .replace("&quot;", "\"")
+  .replace("&apos;", "'")
+  .replace("&amp;", "&")
+}
+
+fn invalid_data(message: &str) -> PluralRulesError {
+  PluralRulesError::InvalidData(message.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+  use super::load_cldr_xml;
+  use super::super::PluralCategory::*;
+
+  #[test]
+  fn test_load_xml() {
+    let rules = load_cldr_xml(r#"<?xml version="1.0" encoding="UTF-8" ?>
+      <supplementalData>
+        <plurals type="cardinal">
+          <pluralRules locales="bm bo">
+            <pluralRule count="other"> @integer 0~15, 100</pluralRule>
+          </pluralRules>
+          <pluralRules locales='pt_PT'>
+            <pluralRule count="one">i = 1 and v = 0 @integer 1</pluralRule>
+            <!-- <pluralRule count="few">i = 2</pluralRule> -->
+            <pluralRule count="other"> @integer 0, 2~16</pluralRule>
+          </pluralRules>
+        </plurals>
+      </supplementalData>
+    "#).unwrap();
+    assert_eq!(rules.len(), 3);
+    assert_eq!(rules["bo"].category(1), Other);
+    assert_eq!(rules["pt-pt"].category(1), One);
+    assert_eq!(rules["pt-pt"].category(2), Other);
+  }
+
+  #[test]
+  fn test_load_xml_errors() {
+    assert!(load_cldr_xml("<pluralRules locales=\"en\">").is_err());
+    assert!(load_cldr_xml("<pluralRules><pluralRule count=\"one\">n = 1</pluralRule></pluralRules>").is_err());
+    assert!(load_cldr_xml("<pluralRules locales=\"en\"><pluralRule count=\"some\">n = 1</pluralRule></pluralRules>").is_err());
+    assert!(load_cldr_xml("<pluralRules locales=\"en\"><pluralRule count=\"one\">n == 1</pluralRule></pluralRules>").is_err());
+    assert!(load_cldr_xml("<!-- <pluralRules").is_err());
+  }
+}
@@ -0,0 +1,498 @@
+//! Utilities to choose the [CLDR plural category][1] of a number in a locale,
+//! which decides the form of a message like “1 file” or “2 files.” Languages
+//! use up to six categories: Russian says “2 файла” but “5 файлов.”
+//!
+//! Rules for common locales are embedded, and rules for more locales may be
+//! loaded from the CLDR `plurals.xml` file or, with the `json` feature, the
+//! CLDR JSON `plurals.json` file.
+//!
+//! [1]: https://cldr.unicode.org/index/cldr-spec/plural-rules
+//!
+//! # Example
+//! ```rust
+//! use inflections::plural_rules::{plural_category, PluralCategory};
+//!
+//! assert_eq!(plural_category("en", 1), PluralCategory::One);
+//! assert_eq!(plural_category("en", 2), PluralCategory::Other);
+//! assert_eq!(plural_category("ru", 2), PluralCategory::Few);
+//! assert_eq!(plural_category("ru", 5), PluralCategory::Many);
+//! ```
+
+mod cldr;
+mod parser;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use self::parser::Condition;
+
+pub use self::cldr::load_cldr_xml;
+#[cfg(feature = "json")]
+pub use self::cldr::load_cldr_json;
+
+/// Chooses the plural category of a number in a locale, such as `"en"` or
+/// `"pt-PT"`. Locales without embedded rules use the `Other` category for every
+/// number.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural_rules::{plural_category, PluralCategory, PluralOperands};
+/// assert_eq!(plural_category("en", 1), PluralCategory::One);
+/// assert_eq!(plural_category("en", "1.0".parse::<PluralOperands>().unwrap()), PluralCategory::Other);
+/// assert_eq!(plural_category("fr", 1.5), PluralCategory::One);
+/// assert_eq!(plural_category("ar", 0), PluralCategory::Zero);
+/// assert_eq!(plural_category("ar", 2), PluralCategory::Two);
+/// assert_eq!(plural_category("ar", 3), PluralCategory::Few);
+/// assert_eq!(plural_category("ar", 11), PluralCategory::Many);
+/// assert_eq!(plural_category("ja", 1), PluralCategory::Other);
+/// ```
+pub fn plural_category<N: Into<PluralOperands>>(locale: &str, n: N) -> PluralCategory {
+  PluralRules::for_locale(locale).map_or(PluralCategory::Other, |rules| rules.category(n))
+}
+
+/// A CLDR plural category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PluralCategory {
+  Zero,
+  One,
+  Two,
+  Few,
+  Many,
+  Other,
+}
+
+impl PluralCategory {
+  /// The CLDR name of the category, like `"few"`.
+  pub fn as_str(&self) -> &'static str {
+    match *self {
+      PluralCategory::Zero => "zero",
+      PluralCategory::One => "one",
+      PluralCategory::Two => "two",
+      PluralCategory::Few => "few",
+      PluralCategory::Many => "many",
+      PluralCategory::Other => "other",
+    }
+  }
+}
+
+impl fmt::Display for PluralCategory {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for PluralCategory {
+  type Err = PluralRulesError;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    match string {
+      "zero" => Ok(PluralCategory::Zero),
+      "one" => Ok(PluralCategory::One),
+      "two" => Ok(PluralCategory::Two),
+      "few" => Ok(PluralCategory::Few),
+      "many" => Ok(PluralCategory::Many),
+      "other" => Ok(PluralCategory::Other),
+      _ => Err(PluralRulesError::UnknownCategory(string.to_owned())),
+    }
+  }
+}
+
+/// The operands of a number used by plural rules. Decimal numbers should be
+/// parsed from strings so their visible fraction digits are kept, because
+/// “1.0” and “1” may be in different categories.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural_rules::PluralOperands;
+/// let operands: PluralOperands = "-1.50".parse().unwrap();
+/// assert_eq!(operands.n, 1.5);
+/// assert_eq!(operands.i, 1);
+/// assert_eq!(operands.v, 2);
+/// assert_eq!(operands.w, 1);
+/// assert_eq!(operands.f, 50);
+/// assert_eq!(operands.t, 5);
+///
+/// let operands: PluralOperands = "1.2c6".parse().unwrap();
+/// assert_eq!(operands.i, 1200000);
+/// assert_eq!(operands.e, 6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PluralOperands {
+  /// The absolute value of the number.
+  pub n: f64,
+  /// The integer digits of the number.
+  pub i: u64,
+  /// The number of visible fraction digits, with trailing zeros.
+  pub v: usize,
+  /// The number of visible fraction digits, without trailing zeros.
+  pub w: usize,
+  /// The visible fraction digits, with trailing zeros.
+  pub f: u64,
+  /// The visible fraction digits, without trailing zeros.
+  pub t: u64,
+  /// The exponent of the number in compact decimal notation.
+  pub e: usize,
+}
+
+impl PluralOperands {
+  /// The value of an operand by its CLDR name. The `c` operand is a synonym
+  /// of `e`.
+  fn value(&self, operand: char) -> f64 {
+    match operand {
+      'i' => self.i as f64,
+      'v' => self.v as f64,
+      'w' => self.w as f64,
+      'f' => self.f as f64,
+      't' => self.t as f64,
+      'c' | 'e' => self.e as f64,
+      _ => self.n,
+    }
+  }
+}
+
+/// The largest compact exponent a number may be parsed with. CLDR only uses
+/// small exponents, like the 6 of “1.2c6” for “1.2 million.”
+const MAX_EXPONENT: usize = 20;
+
+impl FromStr for PluralOperands {
+  type Err = PluralRulesError;
+
+  /// Parses a decimal number like `"-12.50"`, optionally with a compact
+  /// exponent like `"1.2c3"` or `"1.2e3"` of at most 20.
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    let invalid = || PluralRulesError::InvalidNumber(string.to_owned());
+    let unsigned = string.trim_start_matches(['-', '+']);
+    let (mantissa, exponent) = match unsigned.find(['c', 'e']) {
+      Some(i) => (&unsigned[..i], unsigned[i + 1..].parse::<usize>().map_err(|_| invalid())?),
+      None => (unsigned, 0),
+    };
+    if exponent > MAX_EXPONENT {
+      return Err(invalid());
+    }
+    let (integer, fraction) = match mantissa.find('.') {
+      Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+      None => (mantissa, ""),
+    };
+    if integer.is_empty() || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+      return Err(invalid());
+    }
+
+    // Shift the decimal point right by the exponent.
+    let shift = exponent.min(fraction.len());
+    let mut integer = integer.to_owned();
+    integer.push_str(&fraction[..shift]);
+    integer.extend((shift..exponent).map(|_| '0'));
+    let fraction = &fraction[shift..];
+    let trimmed = fraction.trim_end_matches('0');
+
+    let digits = |digits: &str| if digits.is_empty() { Ok(0) } else { digits.parse::<u64>().map_err(|_| invalid()) };
+    let n = format!("{}.{}", integer, fraction).trim_end_matches('.').parse().map_err(|_| invalid())?;
+
+    Ok(PluralOperands {
+      n,
+      i: integer.trim_start_matches('0').parse().unwrap_or(if integer.trim_start_matches('0').is_empty() { 0 } else { u64::MAX }),
+      v: fraction.len(),
+      w: trimmed.len(),
+      f: digits(fraction)?,
+      t: digits(trimmed)?,
+      e: exponent,
+    })
+  }
+}
+
+macro_rules! impl_from_integer {
+  ($($t:ty),*) => {$(
+    impl From<$t> for PluralOperands {
+      #[inline]
+      fn from(n: $t) -> Self {
+        let i = (n as i128).unsigned_abs();
+        PluralOperands {
+          n: i as f64,
+          i: i.min(u64::MAX as u128) as u64,
+          ..PluralOperands::default()
+        }
+      }
+    }
+  )*};
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for PluralOperands {
+  #[inline]
+  fn from(n: u128) -> Self {
+    PluralOperands {
+      n: n as f64,
+      i: n.min(u64::MAX as u128) as u64,
+      ..PluralOperands::default()
+    }
+  }
+}
+
+impl From<f64> for PluralOperands {
+  /// Uses the shortest decimal representation of the number, so `1.0` has no
+  /// visible fraction digits. Parse a string to keep trailing zeros.
+  fn from(n: f64) -> Self {
+    if n.is_finite() {
+      n.to_string().parse().unwrap_or_default()
+    } else {
+      PluralOperands { n: n.abs(), ..PluralOperands::default() }
+    }
+  }
+}
+
+impl From<f32> for PluralOperands {
+  fn from(n: f32) -> Self {
+    if n.is_finite() {
+      n.to_string().parse().unwrap_or_default()
+    } else {
+      PluralOperands { n: n.abs() as f64, ..PluralOperands::default() }
+    }
+  }
+}
+
+/// The plural rules of a locale.
+///
+/// # Example
+/// ```rust
+/// # use inflections::plural_rules::{PluralCategory, PluralRules};
+/// let rules = PluralRules::parse(&[
+///   (PluralCategory::One, "i = 1 and v = 0 @integer 1"),
+///   (PluralCategory::Other, " @integer 0, 2~16, 100, 1000"),
+/// ]).unwrap();
+///
+/// assert_eq!(rules.category(1), PluralCategory::One);
+/// assert_eq!(rules.category(3), PluralCategory::Other);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PluralRules {
+  /// The condition of every category except `Other`.
+  rules: Vec<(PluralCategory, Condition)>,
+}
+
+impl PluralRules {
+  /// Parses plural rules written in the CLDR rule syntax. The rule for the
+  /// `Other` category is ignored, as it applies when no other rule does.
+  ///
+  /// # Errors
+  /// Returns `PluralRulesError::Syntax` if a rule is invalid.
+  pub fn parse(rules: &[(PluralCategory, &str)]) -> Result<Self, PluralRulesError> {
+    let mut parsed = Vec::with_capacity(rules.len());
+    for &(category, rule) in rules {
+      if category != PluralCategory::Other {
+        parsed.push((category, parser::parse(rule)?));
+      }
+    }
+    Ok(PluralRules { rules: parsed })
+  }
+
+  /// The embedded plural rules of a locale, such as `"en"` or `"pt-PT"`. If
+  /// there are no rules for the full locale, the rules for its language are
+  /// used.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::plural_rules::{PluralCategory, PluralRules};
+  /// let rules = PluralRules::for_locale("pt_PT").unwrap();
+  /// assert_eq!(rules.category(0), PluralCategory::Other);
+  /// let rules = PluralRules::for_locale("pt-BR").unwrap();
+  /// assert_eq!(rules.category(0), PluralCategory::One);
+  /// assert!(PluralRules::for_locale("tlh").is_none());
+  /// ```
+  pub fn for_locale(locale: &str) -> Option<&'static PluralRules> {
+    cldr::embedded(locale)
+  }
+
+  /// Chooses the plural category of a number.
+  pub fn category<N: Into<PluralOperands>>(&self, n: N) -> PluralCategory {
+    let operands = n.into();
+    self.rules
+    .iter()
+    .find(|(_, condition)| condition.matches(&operands))
+    .map_or(PluralCategory::Other, |&(category, _)| category)
+  }
+
+  /// The categories used by these rules, including `Other`.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::plural_rules::{PluralCategory, PluralRules};
+  /// let rules = PluralRules::for_locale("ru").unwrap();
+  /// assert_eq!(rules.categories(), vec![
+  ///   PluralCategory::One,
+  ///   PluralCategory::Few,
+  ///   PluralCategory::Many,
+  ///   PluralCategory::Other,
+  /// ]);
+  /// ```
+  pub fn categories(&self) -> Vec<PluralCategory> {
+    let mut categories: Vec<_> = self.rules.iter().map(|&(category, _)| category).collect();
+    categories.push(PluralCategory::Other);
+    categories.sort();
+    categories.dedup();
+    categories
+  }
+}
+
+/// An error parsing plural rules or numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PluralRulesError {
+  /// A rule is not valid CLDR rule syntax.
+  Syntax {
+    /// The invalid rule.
+    rule: String,
+    /// The byte offset of the error in the rule.
+    offset: usize,
+    /// A description of the error.
+    message: String,
+  },
+  /// A category name is not one of the CLDR categories.
+  UnknownCategory(String),
+  /// A number could not be parsed.
+  InvalidNumber(String),
+  /// A CLDR data file is malformed.
+  InvalidData(String),
+}
+
+impl fmt::Display for PluralRulesError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      PluralRulesError::Syntax { ref rule, offset, ref message } => {
+        write!(f, "invalid plural rule `{}` at offset {}: {}", rule, offset, message)
+      },
+      PluralRulesError::UnknownCategory(ref name) => write!(f, "unknown plural category `{}`", name),
+      PluralRulesError::InvalidNumber(ref number) => write!(f, "invalid number `{}`", number),
+      PluralRulesError::InvalidData(ref message) => write!(f, "invalid plural rules data: {}", message),
+    }
+  }
+}
+
+impl Error for PluralRulesError {}
+
+#[cfg(test)]
+mod test {
+  use super::{plural_category, PluralCategory, PluralOperands, PluralRules};
+  use super::PluralCategory::*;
+
+  fn decimal(string: &str) -> PluralOperands {
+    string.parse().unwrap()
+  }
+
+  #[test]
+  fn test_english() {
+    assert_eq!(plural_category("en", 0), Other);
+    assert_eq!(plural_category("en", 1), One);
+    assert_eq!(plural_category("en", -1), One);
+    assert_eq!(plural_category("en-GB", 2), Other);
+    assert_eq!(plural_category("en", decimal("1.0")), Other);
+  }
+
+  #[test]
+  fn test_russian() {
+    let expected = [
+      (1, One), (2, Few), (4, Few), (5, Many), (11, Many), (12, Many), (14, Many),
+      (21, One), (22, Few), (25, Many), (101, One), (111, Many), (1000, Many),
+    ];
+    for &(n, category) in &expected {
+      assert_eq!(plural_category("ru", n), category, "{}", n);
+    }
+    assert_eq!(plural_category("ru", decimal("1.5")), Other);
+  }
+
+  #[test]
+  fn test_belarusian() {
+    let expected = [(1, One), (2, Few), (5, Many), (11, Many), (21, One), (112, Many)];
+    for &(n, category) in &expected {
+      assert_eq!(plural_category("be", n), category, "{}", n);
+    }
+    assert_eq!(plural_category("be", decimal("2.0")), Few);
+    assert_eq!(plural_category("be", decimal("1.0")), One);
+    assert_eq!(plural_category("be", decimal("11.0")), Many);
+    assert_eq!(plural_category("be", decimal("1.5")), Other);
+    assert_eq!(plural_category("ru", decimal("2.0")), Other);
+  }
+
+  #[test]
+  fn test_polish() {
+    assert_eq!(plural_category("pl", 1), One);
+    assert_eq!(plural_category("pl", 3), Few);
+    assert_eq!(plural_category("pl", 5), Many);
+    assert_eq!(plural_category("pl", 21), Many);
+    assert_eq!(plural_category("pl", 22), Few);
+    assert_eq!(plural_category("pl", decimal("1.5")), Other);
+  }
+
+  #[test]
+  fn test_french() {
+    assert_eq!(plural_category("fr", 0), One);
+    assert_eq!(plural_category("fr", decimal("1.5")), One);
+    assert_eq!(plural_category("fr", 2), Other);
+    assert_eq!(plural_category("fr", 1000000), Many);
+    assert_eq!(plural_category("fr", decimal("1c6")), Many);
+  }
+
+  #[test]
+  fn test_decimal_operands() {
+    assert_eq!(plural_category("lv", decimal("0.1")), One);
+    assert_eq!(plural_category("lv", decimal("0.11")), Zero);
+    assert_eq!(plural_category("lt", decimal("0.5")), Many);
+    assert_eq!(plural_category("cs", decimal("1.5")), Many);
+    assert_eq!(plural_category("is", decimal("0.1")), One);
+    assert_eq!(plural_category("hr", decimal("0.1")), One);
+  }
+
+  #[test]
+  fn test_welsh() {
+    let expected = [(0, Zero), (1, One), (2, Two), (3, Few), (6, Many), (4, Other), (7, Other)];
+    for &(n, category) in &expected {
+      assert_eq!(plural_category("cy", n), category);
+    }
+  }
+
+  #[test]
+  fn test_operators() {
+    let rules = PluralRules::parse(&[
+      (Zero, "n is 0"),
+      (One, "n within 0.5..1.5 and n is not 0"),
+      (Two, "n mod 10 in 2 and n not in 12"),
+      (Few, "n % 100 not within 0..10"),
+    ]).unwrap();
+    assert_eq!(rules.category(0), Zero);
+    assert_eq!(rules.category(decimal("0.7")), One);
+    assert_eq!(rules.category(22), Two);
+    assert_eq!(rules.category(12), Few);
+    assert_eq!(rules.category(5), Other);
+  }
+
+  #[test]
+  fn test_syntax_errors() {
+    assert!(PluralRules::parse(&[(One, "i = ")]).is_err());
+    assert!(PluralRules::parse(&[(One, "x = 1")]).is_err());
+    assert!(PluralRules::parse(&[(One, "i = 1 v = 0")]).is_err());
+    assert!(PluralRules::parse(&[(One, "i not 1")]).is_err());
+    assert!(PluralRules::parse(&[(One, "i = 1 # 2")]).is_err());
+  }
+
+  #[test]
+  fn test_operands() {
+    assert_eq!(PluralOperands::from(-5), decimal("5"));
+    assert_eq!(PluralOperands::from(1.5), decimal("1.5"));
+    assert_eq!(decimal("1.2e3").i, 1200);
+    assert_eq!(decimal("1.2e3").v, 0);
+    assert_eq!(decimal("1.234c2").f, 4);
+    assert!("".parse::<PluralOperands>().is_err());
+    assert!("1.x".parse::<PluralOperands>().is_err());
+    assert!(".5".parse::<PluralOperands>().is_err());
+    assert_eq!(decimal("1e20").e, 20);
+    assert!("1e21".parse::<PluralOperands>().is_err());
+    assert!("1e999999999".parse::<PluralOperands>().is_err());
+  }
+
+  #[test]
+  fn test_category_names() {
+    for category in &[Zero, One, Two, Few, Many, Other] {
+      assert_eq!(category.as_str().parse::<PluralCategory>(), Ok(*category));
+    }
+    assert!("several".parse::<PluralCategory>().is_err());
+  }
+}
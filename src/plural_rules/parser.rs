@@ -0,0 +1,259 @@
+//! A parser and evaluator for the [CLDR plural rule syntax][1].
+//!
+//! [1]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+
+use super::{PluralOperands, PluralRulesError};
+
+/// A parsed plural rule condition, which is true if any of its `and`
+/// conditions are true. An empty condition, as used by the `other` category,
+/// is always true.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition(Vec<Vec<Relation>>);
+
+/// A comparison of an operand against a list of ranges.
+#[derive(Clone, Debug, PartialEq)]
+struct Relation {
+  /// The operand being compared.
+  operand: char,
+  /// The value the operand is taken modulo, if any.
+  modulus: Option<f64>,
+  /// Whether the result of the comparison is negated.
+  negated: bool,
+  /// Whether non-integer values may match the ranges.
+  within: bool,
+  /// Inclusive ranges to compare the operand against.
+  ranges: Vec<(f64, f64)>,
+}
+
+impl Condition {
+  /// Checks if the condition is true for some operands.
+  pub fn matches(&self, operands: &PluralOperands) -> bool {
+    self.0.is_empty() ||
+    self.0.iter().any(|relations| relations.iter().all(|relation| relation.matches(operands)))
+  }
+}
+
+impl Relation {
+  /// Checks if the relation is true for some operands.
+  fn matches(&self, operands: &PluralOperands) -> bool {
+    let mut value = operands.value(self.operand);
+    if let Some(modulus) = self.modulus {
+      value %= modulus;
+    }
+    let found = self.ranges.iter().any(|&(low, high)| {
+      (self.within || value.fract() == 0.0) && low <= value && value <= high
+    });
+    found != self.negated
+  }
+}
+
+/// Parses a rule like `"v = 0 and i % 10 = 1 @integer 1, 21"`. Samples after
+/// an `@` are ignored.
+pub fn parse(rule: &str) -> Result<Condition, PluralRulesError> {
+  let source = rule.split('@').next().unwrap_or_default();
+  let mut parser = Parser {
+    rule,
+    tokens: tokenize(rule, source)?,
+    position: 0,
+  };
+  let condition = parser.condition()?;
+  match parser.tokens.get(parser.position) {
+    None => Ok(condition),
+    Some(&(offset, _)) => Err(parser.error(offset, "expected `and` or `or`")),
+  }
+}
+
+/// A token in a plural rule.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+  Word(String),
+  Number(f64),
+  Equals,
+  NotEquals,
+  Percent,
+  Range,
+  Comma,
+}
+
+/// Splits the source of a rule into tokens paired with their byte offsets.
+fn tokenize(rule: &str, source: &str) -> Result<Vec<(usize, Token)>, PluralRulesError> {
+  let mut tokens = Vec::new();
+  let mut chars = source.char_indices().peekable();
+
+  while let Some((offset, c)) = chars.next() {
+    let token = match c {
+      _ if c.is_whitespace() => continue,
+      '=' => Token::Equals,
+      '%' => Token::Percent,
+      ',' => Token::Comma,
+      '!' if chars.peek().map(|&(_, c)| c) == Some('=') => {
+        chars.next();
+        Token::NotEquals
+      },
+      '.' if chars.peek().map(|&(_, c)| c) == Some('.') => {
+        chars.next();
+        Token::Range
+      },
+      _ if c.is_ascii_digit() => {
+        let mut end = offset + 1;
+        while let Some(&(i, c)) = chars.peek() {
+          // A `.` followed by another `.` starts a range, not a fraction.
+          if c.is_ascii_digit() || (c == '.' && !source[i + 1..].starts_with('.')) {
+            end = i + 1;
+            chars.next();
+          } else {
+            break;
+          }
+        }
+        match source[offset..end].parse() {
+          Ok(number) => Token::Number(number),
+          Err(_) => return Err(syntax_error(rule, offset, "invalid number")),
+        }
+      },
+      _ if c.is_ascii_alphabetic() => {
+        let mut end = offset + 1;
+        while let Some(&(i, c)) = chars.peek() {
+          if c.is_ascii_alphabetic() {
+            end = i + 1;
+            chars.next();
+          } else {
+            break;
+          }
+        }
+        Token::Word(source[offset..end].to_owned())
+      },
+      _ => return Err(syntax_error(rule, offset, "unexpected character")),
+    };
+    tokens.push((offset, token));
+  }
+
+  Ok(tokens)
+}
+
+/// A recursive descent parser over the tokens of a rule.
+struct Parser<'a> {
+  rule: &'a str,
+  tokens: Vec<(usize, Token)>,
+  position: usize,
+}
+
+impl<'a> Parser<'a> {
+  /// `condition = and_condition ('or' and_condition)*`
+  fn condition(&mut self) -> Result<Condition, PluralRulesError> {
+    let mut conditions = Vec::new();
+    if self.tokens.is_empty() {
+      return Ok(Condition(conditions));
+    }
+    conditions.push(self.and_condition()?);
+    while self.eat_word("or") {
+      conditions.push(self.and_condition()?);
+    }
+    Ok(Condition(conditions))
+  }
+
+  /// `and_condition = relation ('and' relation)*`
+  fn and_condition(&mut self) -> Result<Vec<Relation>, PluralRulesError> {
+    let mut relations = vec![self.relation()?];
+    while self.eat_word("and") {
+      relations.push(self.relation()?);
+    }
+    Ok(relations)
+  }
+
+  /// `relation = expr ('is' 'not'? | 'not'? 'in' | 'not'? 'within' | '=' | '!=') range_list`
+  fn relation(&mut self) -> Result<Relation, PluralRulesError> {
+    let offset = self.offset();
+    let operand = match self.next() {
+      Some(Token::Word(ref word)) if word.len() == 1 && "nivwftce".contains(word.as_str()) => {
+        word.chars().next().unwrap_or('n')
+      },
+      _ => return Err(self.error(offset, "expected an operand")),
+    };
+
+    let modulus = if self.eat_word("mod") || self.eat(&Token::Percent) {
+      Some(self.number()?)
+    } else {
+      None
+    };
+
+    let offset = self.offset();
+    let (negated, within) = match self.next() {
+      Some(Token::Equals) => (false, false),
+      Some(Token::NotEquals) => (true, false),
+      Some(Token::Word(ref word)) if word == "is" => (self.eat_word("not"), false),
+      Some(Token::Word(ref word)) if word == "in" => (false, false),
+      Some(Token::Word(ref word)) if word == "within" => (false, true),
+      Some(Token::Word(ref word)) if word == "not" => {
+        if self.eat_word("in") {
+          (true, false)
+        } else if self.eat_word("within") {
+          (true, true)
+        } else {
+          return Err(self.error(self.offset(), "expected `in` or `within`"));
+        }
+      },
+      _ => return Err(self.error(offset, "expected a relation")),
+    };
+
+    let mut ranges = vec![self.range()?];
+    while self.eat(&Token::Comma) {
+      ranges.push(self.range()?);
+    }
+
+    Ok(Relation { operand, modulus, negated, within, ranges })
+  }
+
+  /// `range = value ('..' value)?`
+  fn range(&mut self) -> Result<(f64, f64), PluralRulesError> {
+    let low = self.number()?;
+    if self.eat(&Token::Range) {
+      Ok((low, self.number()?))
+    } else {
+      Ok((low, low))
+    }
+  }
+
+  fn number(&mut self) -> Result<f64, PluralRulesError> {
+    let offset = self.offset();
+    match self.next() {
+      Some(Token::Number(number)) => Ok(number),
+      _ => Err(self.error(offset, "expected a number")),
+    }
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.position).map(|(_, token)| token.clone());
+    self.position += 1;
+    token
+  }
+
+  fn eat(&mut self, token: &Token) -> bool {
+    if self.tokens.get(self.position).map(|(_, t)| t) == Some(token) {
+      self.position += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn eat_word(&mut self, word: &str) -> bool {
+    self.eat(&Token::Word(word.to_owned()))
+  }
+
+  /// The byte offset of the next token, or the end of the rule.
+  fn offset(&self) -> usize {
+    self.tokens.get(self.position).map_or(self.rule.len(), |&(offset, _)| offset)
+  }
+
+  fn error(&self, offset: usize, message: &str) -> PluralRulesError {
+    syntax_error(self.rule, offset, message)
+  }
+}
+
+fn syntax_error(rule: &str, offset: usize, message: &str) -> PluralRulesError {
+  PluralRulesError::Syntax {
+    rule: rule.to_owned(),
+    offset,
+    message: message.to_owned(),
+  }
+}
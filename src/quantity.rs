@@ -0,0 +1,156 @@
+//! Utilities to write a count of things as an English noun phrase, like
+//! “1 file” or “3 files,” without hand written plural suffixes.
+//!
+//! # Example
+//! ```rust
+//! use inflections::quantity::{quantify, quantify_with, Quantity};
+//!
+//! assert_eq!(quantify(1, "file"), "1 file".to_owned());
+//! assert_eq!(quantify(3, "file"), "3 files".to_owned());
+//!
+//! let options = Quantity::new().zero("no").spell_out(9);
+//! assert_eq!(quantify_with(0, "file", &options), "no files".to_owned());
+//! assert_eq!(quantify_with(3, "file", &options), "three files".to_owned());
+//! ```
+
+use plural::{pluralize, singularize};
+use words;
+
+/// Writes a count followed by the noun in the singular or the plural.
+///
+/// # Example
+/// ```rust
+/// # use inflections::quantity::quantify;
+/// assert_eq!(quantify(0, "file"), "0 files".to_owned());
+/// assert_eq!(quantify(1, "file"), "1 file".to_owned());
+/// assert_eq!(quantify(1, "files"), "1 file".to_owned());
+/// assert_eq!(quantify(2, "person"), "2 people".to_owned());
+/// assert_eq!(quantify(1500, "sheep"), "1500 sheep".to_owned());
+/// ```
+pub fn quantify(count: usize, noun: &str) -> String {
+  quantify_with(count, noun, &Quantity::new())
+}
+
+/// Writes a count followed by the noun in the singular or the plural using
+/// the options of a `Quantity`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::quantity::{quantify_with, Quantity};
+/// let options = Quantity::new().thousands_separator(',');
+/// assert_eq!(quantify_with(1234567, "file", &options), "1,234,567 files".to_owned());
+/// ```
+pub fn quantify_with(count: usize, noun: &str, options: &Quantity) -> String {
+  let noun = if count == 1 { singularize(noun) } else { pluralize(noun) };
+  let count = match options.zero {
+    Some(ref zero) if count == 0 => zero.clone(),
+    _ if options.spell_out.is_some_and(|max| count <= max) => words::to_words(count as i128),
+    _ => group(count, options.thousands_separator),
+  };
+  if noun.is_empty() { count } else { count + " " + &noun }
+}
+
+/// Options for writing a count of things.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quantity {
+  /// The word written instead of a zero count, like “no.”
+  zero: Option<String>,
+  /// The largest count written in words.
+  spell_out: Option<usize>,
+  /// The character separating groups of three digits.
+  thousands_separator: Option<char>,
+}
+
+impl Quantity {
+  /// Creates options which write every count in digits.
+  pub fn new() -> Self {
+    Quantity::default()
+  }
+
+  /// Writes a word like “no” instead of a zero count.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::quantity::{quantify_with, Quantity};
+  /// let options = Quantity::new().zero("no");
+  /// assert_eq!(quantify_with(0, "match", &options), "no matches".to_owned());
+  /// assert_eq!(quantify_with(2, "match", &options), "2 matches".to_owned());
+  /// ```
+  pub fn zero(mut self, word: &str) -> Self {
+    self.zero = Some(word.to_owned());
+    self
+  }
+
+  /// Writes counts up to `max` in words. Style guides commonly spell out
+  /// counts up to nine.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::quantity::{quantify_with, Quantity};
+  /// let options = Quantity::new().spell_out(99);
+  /// assert_eq!(quantify_with(1, "file", &options), "one file".to_owned());
+  /// assert_eq!(quantify_with(42, "file", &options), "forty-two files".to_owned());
+  /// assert_eq!(quantify_with(100, "file", &options), "100 files".to_owned());
+  /// ```
+  pub fn spell_out(mut self, max: usize) -> Self {
+    self.spell_out = Some(max);
+    self
+  }
+
+  /// Separates groups of three digits with a character like `,`.
+  pub fn thousands_separator(mut self, separator: char) -> Self {
+    self.thousands_separator = Some(separator);
+    self
+  }
+}
+
+/// Writes a count in digits, optionally separating groups of three digits.
+fn group(count: usize, separator: Option<char>) -> String {
+  let digits = count.to_string();
+  let separator = match separator {
+    Some(separator) => separator,
+    None => return digits,
+  };
+  let mut grouped = String::with_capacity(digits.len() * 4 / 3);
+  for (i, digit) in digits.chars().enumerate() {
+    if i > 0 && (digits.len() - i).is_multiple_of(3) {
+      grouped.push(separator);
+    }
+    grouped.push(digit);
+  }
+  grouped
+}
+
+#[cfg(test)]
+mod test {
+  use super::{quantify, quantify_with, Quantity};
+
+  #[test]
+  fn test_quantify() {
+    assert_eq!(quantify(1, "blog post"), "1 blog post");
+    assert_eq!(quantify(2, "blog post"), "2 blog posts");
+    assert_eq!(quantify(2, "mother-in-law"), "2 mothers-in-law");
+    assert_eq!(quantify(1, "mothers-in-law"), "1 mother-in-law");
+    assert_eq!(quantify(3, ""), "3");
+  }
+
+  #[test]
+  fn test_options() {
+    let options = Quantity::new().zero("no").spell_out(20).thousands_separator(',');
+    assert_eq!(quantify_with(0, "file", &options), "no files");
+    assert_eq!(quantify_with(1, "file", &options), "one file");
+    assert_eq!(quantify_with(13, "file", &options), "thirteen files");
+    assert_eq!(quantify_with(21, "file", &options), "21 files");
+    assert_eq!(quantify_with(999, "file", &options), "999 files");
+    assert_eq!(quantify_with(1000, "file", &options), "1,000 files");
+    assert_eq!(quantify_with(100000, "file", &options), "100,000 files");
+  }
+
+  #[test]
+  fn test_spell_out_zero() {
+    let options = Quantity::new().spell_out(9);
+    assert_eq!(quantify_with(0, "file", &options), "zero files");
+    assert_eq!(quantify_with(90, "file", &Quantity::new().spell_out(99)), "ninety files");
+    assert_eq!(quantify_with(1200, "file", &Quantity::new().spell_out(9999)), "one thousand two hundred files");
+  }
+}
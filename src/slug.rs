@@ -0,0 +1,352 @@
+//! Utilities to turn arbitrary text, like the title of a page, into a URL
+//! safe slug. Letters are transliterated to ASCII, punctuation is dropped,
+//! and words are joined with a separator.
+//!
+//! # Example
+//! ```rust
+//! use inflections::slug::slugify;
+//!
+//! assert_eq!(slugify("Crème Brûlée"), "creme-brulee".to_owned());
+//! assert_eq!(slugify("  Hello, World!  "), "hello-world".to_owned());
+//! ```
+
+use case::to_kebab_case;
+use transliterate::{transliterate_with, Transliteration};
+
+/// Converts text into a slug of lower case ASCII letters and digits joined
+/// with `-`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::slug::slugify;
+/// assert_eq!(slugify("Crème Brûlée"), "creme-brulee".to_owned());
+/// assert_eq!(slugify("Don't Stop Me Now"), "dont-stop-me-now".to_owned());
+/// assert_eq!(slugify("Rust 2024 — what's new?"), "rust-2024-whats-new".to_owned());
+/// assert_eq!(slugify("Push to GitHub"), "push-to-github".to_owned());
+/// ```
+pub fn slugify(text: &str) -> String {
+  slugify_with(text, &Slug::new())
+}
+
+/// Converts text into a slug using the separator and maximum length of
+/// `options`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::slug::{slugify_with, Slug};
+/// let options = Slug::new().separator('_').max_length(20);
+/// assert_eq!(slugify_with("The Quick Brown Fox Jumps", &options), "the_quick_brown_fox".to_owned());
+/// ```
+pub fn slugify_with(text: &str, options: &Slug) -> String {
+  let mut plain = String::with_capacity(text.len());
+  for c in transliterate_with(text, &options.transliteration).chars() {
+    match c {
+      // Apostrophes join the parts of a word like “don't.”
+      '\'' => {},
+      c if c.is_ascii_alphanumeric() => plain.push(c),
+      _ => plain.push(' '),
+    }
+  }
+
+  // Lower casing first keeps `to_kebab_case` from breaking words like
+  // “GitHub” in two.
+  let kebab = to_kebab_case(&plain.to_lowercase());
+  let words: Vec<_> = kebab.split('-').filter(|word| !word.is_empty()).collect();
+  join_words(&words, options.separator, options.max_length)
+}
+
+/// Converts text into a slug which is not taken, appending `-2`, `-3`, and
+/// so on to a slug until `is_taken` returns false. Returns `None` if the slug
+/// is taken and has no words to append a number to.
+///
+/// # Example
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use inflections::slug::unique_slug;
+/// let taken: HashSet<_> = vec!["hello-world", "hello-world-2", ""].into_iter().collect();
+/// assert_eq!(unique_slug("Hello World", &|slug| taken.contains(slug)), Some("hello-world-3".to_owned()));
+/// assert_eq!(unique_slug("Goodbye World", &|slug| taken.contains(slug)), Some("goodbye-world".to_owned()));
+/// assert_eq!(unique_slug("!!!", &|slug| taken.contains(slug)), None);
+/// ```
+pub fn unique_slug(text: &str, is_taken: &dyn Fn(&str) -> bool) -> Option<String> {
+  unique_slug_with(text, &Slug::new(), is_taken)
+}
+
+/// Converts text into a slug which is not taken using the separator,
+/// maximum length, and suffix of `options`. Words are left out of the slug
+/// to make room for the suffix within the maximum length. Returns `None` if
+/// no word fits next to the suffix, as a suffix alone would not describe the
+/// text.
+///
+/// # Example
+/// ```rust
+/// # use inflections::slug::{unique_slug_with, Slug, Suffix};
+/// let options = Slug::new().max_length(12);
+/// let taken = |slug: &str| slug == "hello-world";
+/// assert_eq!(unique_slug_with("Hello World", &options, &taken), Some("hello-2".to_owned()));
+///
+/// let options = Slug::new().suffix(Suffix::Hash);
+/// assert_eq!(unique_slug_with("Hello World", &options, &taken), Some("hello-world-be370c".to_owned()));
+///
+/// let options = Slug::new().max_length(6);
+/// assert_eq!(unique_slug_with("Hello World", &options, &|slug| slug == "hello"), None);
+/// ```
+pub fn unique_slug_with(text: &str, options: &Slug, is_taken: &dyn Fn(&str) -> bool) -> Option<String> {
+  let slug = slugify_with(text, options);
+  if !is_taken(&slug) {
+    return Some(slug);
+  }
+  let words: Vec<_> = slug.split(options.separator).filter(|word| !word.is_empty()).collect();
+  for n in 2.. {
+    let suffix = match options.suffix {
+      Suffix::Counter => n.to_string(),
+      Suffix::Hash => short_hash(&slug, n),
+    };
+    let max_length = options.max_length.map(|max| max.saturating_sub(suffix.len() + 1));
+    let base = join_words(&words, options.separator, max_length);
+    // Suffixes only grow longer, so no later suffix fits either.
+    if base.is_empty() {
+      return None;
+    }
+    let candidate = format!("{}{}{}", base, options.separator, suffix);
+    if !is_taken(&candidate) {
+      return Some(candidate);
+    }
+  }
+  None
+}
+
+/// Converts a name into an identifier which is not taken, appending a number
+/// to the name in the case of `convert` until `is_taken` returns false.
+///
+/// # Example
+/// ```rust
+/// # use inflections::case::{to_pascal_case, to_snake_case};
+/// # use inflections::slug::unique_name;
+/// let taken = |name: &str| ["user", "user_2", "User"].contains(&name);
+/// assert_eq!(unique_name("User", to_snake_case, &taken), "user_3".to_owned());
+/// assert_eq!(unique_name("user", to_pascal_case, &taken), "User2".to_owned());
+/// ```
+pub fn unique_name<F: Fn(&str) -> String>(name: &str, convert: F, is_taken: &dyn Fn(&str) -> bool) -> String {
+  let converted = convert(name);
+  if !is_taken(&converted) {
+    return converted;
+  }
+  (2..)
+  .map(|n: usize| convert(&format!("{} {}", name, n)))
+  .find(|candidate| !is_taken(candidate))
+  .unwrap_or(converted)
+}
+
+/// Joins as many words as fit in `max_length` with a separator. Words are
+/// never cut, so the slug is empty if the first word does not fit.
+fn join_words(words: &[&str], separator: char, max_length: Option<usize>) -> String {
+  let mut slug = String::new();
+  for word in words {
+    let len = if slug.is_empty() { word.len() } else { slug.len() + 1 + word.len() };
+    if max_length.is_some_and(|max| len > max) {
+      break;
+    }
+    if !slug.is_empty() {
+      slug.push(separator);
+    }
+    slug.push_str(word);
+  }
+  slug
+}
+
+/// A short hexadecimal FNV-1a hash of a slug and the number of the attempt
+/// to make it unique.
+fn short_hash(slug: &str, attempt: u64) -> String {
+  let hash = slug
+  .bytes()
+  .chain(attempt.to_le_bytes().iter().cloned())
+  .fold(0x811c_9dc5_u32, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193));
+  format!("{:06x}", hash & 0xff_ffff)
+}
+
+/// The suffix which makes a slug unique.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Suffix {
+  /// A number counting up from 2, like `hello-world-2`.
+  #[default]
+  Counter,
+  /// Six hexadecimal digits derived from the slug, like `hello-world-be370c`.
+  Hash,
+}
+
+/// Options for writing a slug.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Slug {
+  /// The character joining the words of a slug.
+  separator: char,
+  /// The largest number of characters in a slug.
+  max_length: Option<usize>,
+  /// How letters are written in ASCII.
+  transliteration: Transliteration,
+  /// The suffix which makes a slug unique.
+  suffix: Suffix,
+}
+
+impl Default for Slug {
+  fn default() -> Self {
+    Slug {
+      separator: '-',
+      max_length: None,
+      transliteration: Transliteration::new().fill(" "),
+      suffix: Suffix::Counter,
+    }
+  }
+}
+
+impl Slug {
+  /// Creates options which join words with `-` and allow slugs of any
+  /// length.
+  pub fn new() -> Self {
+    Slug::default()
+  }
+
+  /// Joins the words of a slug with a character like `_`.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::slug::{slugify_with, Slug};
+  /// let options = Slug::new().separator('_');
+  /// assert_eq!(slugify_with("Hello World", &options), "hello_world".to_owned());
+  /// ```
+  pub fn separator(mut self, separator: char) -> Self {
+    self.separator = separator;
+    self
+  }
+
+  /// Limits slugs to `max` characters. Words which do not fit are left out
+  /// instead of being cut, so the slug is empty if the first word alone is
+  /// too long.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::slug::{slugify_with, Slug};
+  /// let options = Slug::new().max_length(12);
+  /// assert_eq!(slugify_with("Hello Wonderful World", &options), "hello".to_owned());
+  /// assert_eq!(slugify_with("Supercalifragilistic", &options), "".to_owned());
+  /// ```
+  pub fn max_length(mut self, max: usize) -> Self {
+    self.max_length = Some(max);
+    self
+  }
+
+  /// Transliterates letters following the conventions of a locale.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::slug::{slugify_with, Slug};
+  /// let options = Slug::new().locale("de");
+  /// assert_eq!(slugify_with("Über Größe", &options), "ueber-groesse".to_owned());
+  /// ```
+  pub fn locale(mut self, locale: &str) -> Self {
+    self.transliteration = self.transliteration.locale(locale);
+    self
+  }
+
+  /// Makes a taken slug unique with a counter or a hash.
+  pub fn suffix(mut self, suffix: Suffix) -> Self {
+    self.suffix = suffix;
+    self
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use case::{to_camel_case, to_constant_case, to_kebab_case};
+  use super::{slugify, slugify_with, unique_name, unique_slug, unique_slug_with, Slug, Suffix};
+
+  const SLUGS: &[(&str, &str)] = &[
+    ("Crème Brûlée", "creme-brulee"),
+    ("Ærøskøbing Straße", "aeroskobing-strasse"),
+    ("Łódź", "lodz"),
+    ("Москва – столица", "moskva-stolitsa"),
+    ("© 2024 Ελλάδα", "c-2024-ellada"),
+    ("hello---world___again", "hello-world-again"),
+    ("C'est la vie", "cest-la-vie"),
+    ("50% off: today only!", "50-off-today-only"),
+    ("snake_case and camelCase", "snake-case-and-camelcase"),
+    ("日本語", ""),
+    ("", ""),
+  ];
+
+  #[test]
+  fn test_slugify() {
+    for &(text, expected) in SLUGS {
+      assert_eq!(slugify(text), expected);
+    }
+  }
+
+  #[test]
+  fn test_max_length() {
+    let options = Slug::new().max_length(11);
+    assert_eq!(slugify_with("hello world again", &options), "hello-world");
+    assert_eq!(slugify_with("hello worlds", &options), "hello");
+    assert_eq!(slugify_with("", &options), "");
+    assert_eq!(slugify_with("hello", &Slug::new().max_length(0)), "");
+    assert_eq!(slugify_with("Internationalization rocks", &Slug::new().max_length(10)), "");
+    assert_eq!(slugify_with("Internationalization rocks", &Slug::new().max_length(20)), "internationalization");
+  }
+
+  #[test]
+  fn test_unique_slug() {
+    let taken = |slug: &str| ["a", "a-2", "a-3", "b"].contains(&slug);
+    assert_eq!(unique_slug("A", &taken).unwrap(), "a-4");
+    assert_eq!(unique_slug("B", &taken).unwrap(), "b-2");
+    assert_eq!(unique_slug("C", &taken).unwrap(), "c");
+    let options = Slug::new().separator('_');
+    assert_eq!(unique_slug_with("hello world", &options, &|slug| slug == "hello_world").unwrap(), "hello_world_2");
+  }
+
+  #[test]
+  fn test_unique_slug_max_length() {
+    let options = Slug::new().max_length(7);
+    let taken = |slug: &str| ["abcde", "abcde-2", "abcde-3"].contains(&slug);
+    assert_eq!(unique_slug_with("abcde fghij", &options, &taken).unwrap(), "abcde-4");
+    let options = Slug::new().max_length(2);
+    assert_eq!(unique_slug_with("ab", &options, &|slug| slug == "ab"), None);
+    let options = Slug::new().max_length(3).suffix(Suffix::Hash);
+    assert_eq!(unique_slug_with("hel", &options, &|slug| slug == "hel"), None);
+    let options = Slug::new().max_length(4);
+    let taken = |slug: &str| slug == "ab" || (slug.starts_with("ab-") && slug.len() <= 4);
+    assert_eq!(unique_slug_with("ab", &options, &taken), None);
+  }
+
+  #[test]
+  fn test_unique_slug_within_max_length() {
+    for max in 0..16 {
+      for &suffix in &[Suffix::Counter, Suffix::Hash] {
+        let options = Slug::new().max_length(max).suffix(suffix);
+        let taken = |slug: &str| slug.len() < 9;
+        match unique_slug_with("ab cd ef gh", &options, &taken) {
+          Some(slug) => assert!(slug.len() <= max && slug.starts_with("ab-")),
+          None => assert!(max < 12),
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_unique_slug_hash() {
+    let options = Slug::new().suffix(Suffix::Hash);
+    let first = unique_slug_with("hello", &options, &|slug| slug == "hello").unwrap();
+    let second = unique_slug_with("hello", &options, &|slug| slug == "hello" || slug == first).unwrap();
+    assert_eq!(first.len(), "hello-".len() + 6);
+    assert!(first.starts_with("hello-"));
+    assert!(second.starts_with("hello-"));
+    assert_ne!(first, second);
+  }
+
+  #[test]
+  fn test_unique_name() {
+    let taken = |name: &str| ["userName", "USER_NAME", "user-name", "user-name-2"].contains(&name);
+    assert_eq!(unique_name("user name", to_camel_case, &taken), "userName2");
+    assert_eq!(unique_name("user name", to_constant_case, &taken), "USER_NAME_2");
+    assert_eq!(unique_name("user name", to_kebab_case, &taken), "user-name-3");
+    assert_eq!(unique_name("post", to_kebab_case, &taken), "post");
+  }
+}
@@ -0,0 +1,130 @@
+//! Utilities to write headings in title case following a style guide. Unlike
+//! `to_title_case`, which capitalizes every word, minor words like articles,
+//! short conjunctions, and prepositions are left in lower case.
+//!
+//! The first and last words of a title and of a subtitle after a colon are
+//! always capitalized.
+//!
+//! # Example
+//! ```rust
+//! use inflections::title::{titleize, TitleStyle};
+//!
+//! assert_eq!(titleize("the lord of the rings", TitleStyle::Chicago), "The Lord of the Rings".to_owned());
+//! assert_eq!(titleize("a walk through the woods", TitleStyle::Ap), "A Walk Through the Woods".to_owned());
+//! assert_eq!(titleize("a walk through the woods", TitleStyle::Chicago), "A Walk through the Woods".to_owned());
+//! ```
+
+use inflector::default_inflector;
+
+/// Converts a heading into title case following a style guide, using the
+/// acronyms of the default `Inflector`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::title::{titleize, TitleStyle};
+/// assert_eq!(titleize("war and peace", TitleStyle::Mla), "War and Peace".to_owned());
+/// assert_eq!(titleize("what it is for", TitleStyle::Apa), "What It Is For".to_owned());
+/// assert_eq!(titleize("rust: a guide to the language", TitleStyle::Chicago), "Rust: A Guide to the Language".to_owned());
+/// ```
+pub fn titleize(string: &str, style: TitleStyle) -> String {
+  default_inflector().titleize(string, style)
+}
+
+/// A style guide deciding which words of a title are left in lower case.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TitleStyle {
+  /// The Associated Press Stylebook: articles, and conjunctions and
+  /// prepositions of three letters or fewer, are lower case.
+  Ap,
+  /// The Chicago Manual of Style: articles, coordinating conjunctions, and
+  /// prepositions of any length are lower case.
+  #[default]
+  Chicago,
+  /// The American Psychological Association style: articles, and
+  /// conjunctions and prepositions of three letters or fewer, are lower case.
+  Apa,
+  /// The Modern Language Association style: articles, coordinating
+  /// conjunctions, prepositions of any length, and the infinitive “to” are
+  /// lower case.
+  Mla,
+}
+
+impl TitleStyle {
+  /// Checks if a lower case word is left in lower case in the middle of a
+  /// title.
+  pub(crate) fn is_minor(self, word: &str) -> bool {
+    let short = word.chars().count() <= 3;
+    match self {
+      TitleStyle::Ap => {
+        ARTICLES.contains(&word) || (short && (CONJUNCTIONS.contains(&word) || PREPOSITIONS.contains(&word)))
+      },
+      TitleStyle::Apa => {
+        ARTICLES.contains(&word) ||
+        (short && (CONJUNCTIONS.contains(&word) || SUBORDINATORS.contains(&word) || PREPOSITIONS.contains(&word)))
+      },
+      TitleStyle::Chicago => {
+        ARTICLES.contains(&word) || (CONJUNCTIONS.contains(&word) && !["so", "yet"].contains(&word)) ||
+        PREPOSITIONS.contains(&word) || word == "as"
+      },
+      TitleStyle::Mla => {
+        ARTICLES.contains(&word) || CONJUNCTIONS.contains(&word) || PREPOSITIONS.contains(&word)
+      },
+    }
+  }
+}
+
+const ARTICLES: &[&str] = &["a", "an", "the"];
+
+/// Coordinating conjunctions.
+const CONJUNCTIONS: &[&str] = &["and", "but", "for", "nor", "or", "so", "yet"];
+
+/// Short subordinating conjunctions.
+const SUBORDINATORS: &[&str] = &["as", "if"];
+
+const PREPOSITIONS: &[&str] = &[
+  "aboard", "about", "above", "across", "after", "against", "along", "amid", "among", "around", "as",
+  "at", "before", "behind", "below", "beneath", "beside", "besides", "between", "beyond", "by",
+  "despite", "down", "during", "except", "for", "from", "in", "inside", "into", "like", "near", "of",
+  "off", "on", "onto", "opposite", "out", "outside", "over", "past", "per", "since", "than",
+  "through", "throughout", "till", "to", "toward", "towards", "under", "underneath", "unlike",
+  "until", "up", "upon", "via", "with", "within", "without",
+];
+
+#[cfg(test)]
+mod test {
+  use super::{titleize, TitleStyle};
+
+  const TITLES: &[(&str, &str, &str, &str, &str)] = &[
+    // (input, AP, Chicago, APA, MLA)
+    ("the lord of the rings", "The Lord of the Rings", "The Lord of the Rings", "The Lord of the Rings", "The Lord of the Rings"),
+    ("a tale of two cities", "A Tale of Two Cities", "A Tale of Two Cities", "A Tale of Two Cities", "A Tale of Two Cities"),
+    ("gone with the wind", "Gone With the Wind", "Gone with the Wind", "Gone With the Wind", "Gone with the Wind"),
+    ("to kill a mockingbird", "To Kill a Mockingbird", "To Kill a Mockingbird", "To Kill a Mockingbird", "To Kill a Mockingbird"),
+    ("slow but sure", "Slow but Sure", "Slow but Sure", "Slow but Sure", "Slow but Sure"),
+    ("fast yet careful", "Fast yet Careful", "Fast Yet Careful", "Fast yet Careful", "Fast yet Careful"),
+    ("known as the best", "Known as the Best", "Known as the Best", "Known as the Best", "Known as the Best"),
+    ("what if it rains", "What If It Rains", "What If It Rains", "What if It Rains", "What If It Rains"),
+    ("the man in", "The Man In", "The Man In", "The Man In", "The Man In"),
+    ("ROLL OVER and play dead", "Roll Over and Play Dead", "Roll over and Play Dead", "Roll Over and Play Dead", "Roll over and Play Dead"),
+  ];
+
+  #[test]
+  fn test_styles() {
+    for &(input, ap, chicago, apa, mla) in TITLES {
+      assert_eq!(titleize(input, TitleStyle::Ap), ap);
+      assert_eq!(titleize(input, TitleStyle::Chicago), chicago);
+      assert_eq!(titleize(input, TitleStyle::Apa), apa);
+      assert_eq!(titleize(input, TitleStyle::Mla), mla);
+    }
+  }
+
+  #[test]
+  fn test_punctuation() {
+    let style = TitleStyle::Chicago;
+    assert_eq!(titleize("star wars: the rise of skywalker", style), "Star Wars: The Rise of Skywalker");
+    assert_eq!(titleize("what is it for? a guide", style), "What Is It For? A Guide");
+    assert_eq!(titleize("  \"the end of the road\"  ", style), "  \"The End of the Road\"  ");
+    assert_eq!(titleize("state-of-the-art design", style), "State-of-the-Art Design");
+    assert_eq!(titleize("", style), "");
+  }
+}
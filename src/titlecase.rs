@@ -0,0 +1,179 @@
+//! The titlecase mappings of characters which are not written like their
+//! uppercase mappings at the start of a word, like the digraph “ǅ” or the
+//! ligature “ﬁ.”
+//!
+//! Generated by `scripts/titlecase.py` from the Unicode 14.0.0 character
+//! database. Do not edit by hand.
+
+use std::char::ToUppercase;
+use std::str::Chars;
+
+/// Converts a character into its titlecase mapping, which may be more than
+/// one character.
+#[inline]
+pub(crate) fn to_titlecase(c: char) -> ToTitlecase {
+  match TITLECASE.binary_search_by_key(&c, |&(key, _)| key) {
+    Ok(i) => ToTitlecase::Mapped(TITLECASE[i].1.chars()),
+    Err(_) => ToTitlecase::Upper(c.to_uppercase()),
+  }
+}
+
+/// An iterator over the titlecase mapping of a character.
+#[derive(Clone, Debug)]
+pub(crate) enum ToTitlecase {
+  /// A mapping from the table.
+  Mapped(Chars<'static>),
+  /// A character whose titlecase is its uppercase.
+  Upper(ToUppercase),
+}
+
+impl Iterator for ToTitlecase {
+  type Item = char;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match *self {
+      ToTitlecase::Mapped(ref mut chars) => chars.next(),
+      ToTitlecase::Upper(ref mut upper) => upper.next(),
+    }
+  }
+}
+
+/// Characters and their titlecase mappings, sorted by character.
+const TITLECASE: &[(char, &str)] = &[
+  ('\u{df}', "Ss"),
+  ('\u{1c4}', "ǅ"),
+  ('\u{1c5}', "ǅ"),
+  ('\u{1c6}', "ǅ"),
+  ('\u{1c7}', "ǈ"),
+  ('\u{1c8}', "ǈ"),
+  ('\u{1c9}', "ǈ"),
+  ('\u{1ca}', "ǋ"),
+  ('\u{1cb}', "ǋ"),
+  ('\u{1cc}', "ǋ"),
+  ('\u{1f1}', "ǲ"),
+  ('\u{1f2}', "ǲ"),
+  ('\u{1f3}', "ǲ"),
+  ('\u{587}', "Եւ"),
+  ('\u{10d0}', "ა"),
+  ('\u{10d1}', "ბ"),
+  ('\u{10d2}', "გ"),
+  ('\u{10d3}', "დ"),
+  ('\u{10d4}', "ე"),
+  ('\u{10d5}', "ვ"),
+  ('\u{10d6}', "ზ"),
+  ('\u{10d7}', "თ"),
+  ('\u{10d8}', "ი"),
+  ('\u{10d9}', "კ"),
+  ('\u{10da}', "ლ"),
+  ('\u{10db}', "მ"),
+  ('\u{10dc}', "ნ"),
+  ('\u{10dd}', "ო"),
+  ('\u{10de}', "პ"),
+  ('\u{10df}', "ჟ"),
+  ('\u{10e0}', "რ"),
+  ('\u{10e1}', "ს"),
+  ('\u{10e2}', "ტ"),
+  ('\u{10e3}', "უ"),
+  ('\u{10e4}', "ფ"),
+  ('\u{10e5}', "ქ"),
+  ('\u{10e6}', "ღ"),
+  ('\u{10e7}', "ყ"),
+  ('\u{10e8}', "შ"),
+  ('\u{10e9}', "ჩ"),
+  ('\u{10ea}', "ც"),
+  ('\u{10eb}', "ძ"),
+  ('\u{10ec}', "წ"),
+  ('\u{10ed}', "ჭ"),
+  ('\u{10ee}', "ხ"),
+  ('\u{10ef}', "ჯ"),
+  ('\u{10f0}', "ჰ"),
+  ('\u{10f1}', "ჱ"),
+  ('\u{10f2}', "ჲ"),
+  ('\u{10f3}', "ჳ"),
+  ('\u{10f4}', "ჴ"),
+  ('\u{10f5}', "ჵ"),
+  ('\u{10f6}', "ჶ"),
+  ('\u{10f7}', "ჷ"),
+  ('\u{10f8}', "ჸ"),
+  ('\u{10f9}', "ჹ"),
+  ('\u{10fa}', "ჺ"),
+  ('\u{10fd}', "ჽ"),
+  ('\u{10fe}', "ჾ"),
+  ('\u{10ff}', "ჿ"),
+  ('\u{1f80}', "ᾈ"),
+  ('\u{1f81}', "ᾉ"),
+  ('\u{1f82}', "ᾊ"),
+  ('\u{1f83}', "ᾋ"),
+  ('\u{1f84}', "ᾌ"),
+  ('\u{1f85}', "ᾍ"),
+  ('\u{1f86}', "ᾎ"),
+  ('\u{1f87}', "ᾏ"),
+  ('\u{1f88}', "ᾈ"),
+  ('\u{1f89}', "ᾉ"),
+  ('\u{1f8a}', "ᾊ"),
+  ('\u{1f8b}', "ᾋ"),
+  ('\u{1f8c}', "ᾌ"),
+  ('\u{1f8d}', "ᾍ"),
+  ('\u{1f8e}', "ᾎ"),
+  ('\u{1f8f}', "ᾏ"),
+  ('\u{1f90}', "ᾘ"),
+  ('\u{1f91}', "ᾙ"),
+  ('\u{1f92}', "ᾚ"),
+  ('\u{1f93}', "ᾛ"),
+  ('\u{1f94}', "ᾜ"),
+  ('\u{1f95}', "ᾝ"),
+  ('\u{1f96}', "ᾞ"),
+  ('\u{1f97}', "ᾟ"),
+  ('\u{1f98}', "ᾘ"),
+  ('\u{1f99}', "ᾙ"),
+  ('\u{1f9a}', "ᾚ"),
+  ('\u{1f9b}', "ᾛ"),
+  ('\u{1f9c}', "ᾜ"),
+  ('\u{1f9d}', "ᾝ"),
+  ('\u{1f9e}', "ᾞ"),
+  ('\u{1f9f}', "ᾟ"),
+  ('\u{1fa0}', "ᾨ"),
+  ('\u{1fa1}', "ᾩ"),
+  ('\u{1fa2}', "ᾪ"),
+  ('\u{1fa3}', "ᾫ"),
+  ('\u{1fa4}', "ᾬ"),
+  ('\u{1fa5}', "ᾭ"),
+  ('\u{1fa6}', "ᾮ"),
+  ('\u{1fa7}', "ᾯ"),
+  ('\u{1fa8}', "ᾨ"),
+  ('\u{1fa9}', "ᾩ"),
+  ('\u{1faa}', "ᾪ"),
+  ('\u{1fab}', "ᾫ"),
+  ('\u{1fac}', "ᾬ"),
+  ('\u{1fad}', "ᾭ"),
+  ('\u{1fae}', "ᾮ"),
+  ('\u{1faf}', "ᾯ"),
+  ('\u{1fb2}', "Ὰͅ"),
+  ('\u{1fb3}', "ᾼ"),
+  ('\u{1fb4}', "Άͅ"),
+  ('\u{1fb7}', "ᾼ͂"),
+  ('\u{1fbc}', "ᾼ"),
+  ('\u{1fc2}', "Ὴͅ"),
+  ('\u{1fc3}', "ῌ"),
+  ('\u{1fc4}', "Ήͅ"),
+  ('\u{1fc7}', "ῌ͂"),
+  ('\u{1fcc}', "ῌ"),
+  ('\u{1ff2}', "Ὼͅ"),
+  ('\u{1ff3}', "ῼ"),
+  ('\u{1ff4}', "Ώͅ"),
+  ('\u{1ff7}', "ῼ͂"),
+  ('\u{1ffc}', "ῼ"),
+  ('\u{fb00}', "Ff"),
+  ('\u{fb01}', "Fi"),
+  ('\u{fb02}', "Fl"),
+  ('\u{fb03}', "Ffi"),
+  ('\u{fb04}', "Ffl"),
+  ('\u{fb05}', "St"),
+  ('\u{fb06}', "St"),
+  ('\u{fb13}', "Մն"),
+  ('\u{fb14}', "Մե"),
+  ('\u{fb15}', "Մի"),
+  ('\u{fb16}', "Վն"),
+  ('\u{fb17}', "Մխ"),
+];
@@ -0,0 +1,279 @@
+//! Utilities to write Unicode text in plain ASCII, which is useful before
+//! generating identifiers, file names, or slugs. A compact table covers
+//! Latin letters with diacritics, Greek, Cyrillic, and common symbols, and
+//! locales may override how letters like “ä” are written.
+//!
+//! Transliteration may be used before any `to_*_case` function.
+//!
+//! # Example
+//! ```rust
+//! use inflections::case::to_snake_case;
+//! use inflections::transliterate::{transliterate, transliterate_with, Transliteration};
+//!
+//! assert_eq!(transliterate("Crème Brûlée"), "Creme Brulee".to_owned());
+//! assert_eq!(transliterate("Москва"), "Moskva".to_owned());
+//!
+//! let german = Transliteration::new().locale("de");
+//! assert_eq!(to_snake_case(&transliterate_with("Größe", &german)), "groesse".to_owned());
+//! ```
+
+use inflector::language;
+
+/// Writes text in plain ASCII, replacing characters which can not be
+/// transliterated with `?`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::transliterate::transliterate;
+/// assert_eq!(transliterate("Ærøskøbing"), "Aeroskobing".to_owned());
+/// assert_eq!(transliterate("Ελλάδα"), "Ellada".to_owned());
+/// assert_eq!(transliterate("ЩУКА"), "SHCHUKA".to_owned());
+/// assert_eq!(transliterate("“Hi” — © 2024"), "\"Hi\" - (c) 2024".to_owned());
+/// assert_eq!(transliterate("日本"), "??".to_owned());
+/// ```
+pub fn transliterate(string: &str) -> String {
+  transliterate_with(string, &Transliteration::new())
+}
+
+/// Writes text in plain ASCII using the locale, replacements, and fill of
+/// `options`.
+///
+/// # Example
+/// ```rust
+/// # use inflections::transliterate::{transliterate_with, Transliteration};
+/// let options = Transliteration::new().locale("da").fill("");
+/// assert_eq!(transliterate_with("Ørsted 日本", &options), "Oersted ".to_owned());
+/// ```
+pub fn transliterate_with(string: &str, options: &Transliteration) -> String {
+  let chars: Vec<char> = string.chars().collect();
+  let mut ascii = String::with_capacity(string.len());
+
+  for (i, &c) in chars.iter().enumerate() {
+    if c.is_ascii() && options.replacements.iter().all(|&(k, _)| k != c) {
+      ascii.push(c);
+      continue;
+    }
+    match options.replacement(c) {
+      Some((replacement, false)) => ascii.push_str(replacement),
+      // A replacement for an upper case letter is in upper case next to
+      // other upper case letters, like “AE” in “ÄRGER,” and capitalized
+      // otherwise, like “Ae” in “Ärger.”
+      Some((replacement, true)) => {
+        let next = chars.get(i + 1).cloned().filter(|c| c.is_alphabetic());
+        let previous = i.checked_sub(1).map(|i| chars[i]).filter(|c| c.is_alphabetic());
+        if next.or(previous).is_some_and(|c| c.is_uppercase()) {
+          ascii.push_str(&replacement.to_uppercase());
+        } else {
+          let mut letters = replacement.chars();
+          ascii.extend(letters.next().map(|c| c.to_ascii_uppercase()));
+          ascii.extend(letters);
+        }
+      },
+      None if is_combining_mark(c) => {},
+      None => ascii.push_str(&options.fill),
+    }
+  }
+
+  ascii
+}
+
+/// Options for transliterating text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transliteration {
+  /// The text written instead of characters which can not be transliterated.
+  fill: String,
+  /// The replacements of a locale, which take precedence over the table.
+  locale: &'static [(&'static str, &'static str)],
+  /// Custom replacements, which take precedence over everything else.
+  replacements: Vec<(char, String)>,
+}
+
+impl Default for Transliteration {
+  fn default() -> Self {
+    Transliteration {
+      fill: "?".to_owned(),
+      locale: &[],
+      replacements: Vec::new(),
+    }
+  }
+}
+
+impl Transliteration {
+  /// Creates options which use the built-in table and replace characters
+  /// which can not be transliterated with `?`.
+  pub fn new() -> Self {
+    Transliteration::default()
+  }
+
+  /// Writes `fill` instead of characters which can not be transliterated.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::transliterate::{transliterate_with, Transliteration};
+  /// let options = Transliteration::new().fill("_");
+  /// assert_eq!(transliterate_with("a☃b", &options), "a_b".to_owned());
+  /// ```
+  pub fn fill(mut self, fill: &str) -> Self {
+    self.fill = fill.to_owned();
+    self
+  }
+
+  /// Uses the conventions of a locale, like “ae” for “ä” in German or “oe”
+  /// for “ø” in Danish and Norwegian. Locales without conventions of their
+  /// own use the built-in table.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::transliterate::{transliterate_with, Transliteration};
+  /// let german = Transliteration::new().locale("de-AT");
+  /// assert_eq!(transliterate_with("Müller", &german), "Mueller".to_owned());
+  /// let swedish = Transliteration::new().locale("sv");
+  /// assert_eq!(transliterate_with("Müller", &swedish), "Muller".to_owned());
+  /// ```
+  pub fn locale(mut self, locale: &str) -> Self {
+    self.locale = match language(locale).as_str() {
+      "de" => GERMAN,
+      "da" | "nb" | "nn" | "no" => DANISH,
+      _ => &[],
+    };
+    self
+  }
+
+  /// Writes `replacement` instead of a character. A replacement for a lower
+  /// case letter is also used for its upper case letter.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::transliterate::{transliterate_with, Transliteration};
+  /// let options = Transliteration::new().replace('€', "euro").replace('&', "and");
+  /// assert_eq!(transliterate_with("5€ & up", &options), "5euro and up".to_owned());
+  /// ```
+  pub fn replace(mut self, c: char, replacement: &str) -> Self {
+    self.replacements.push((c, replacement.to_owned()));
+    self
+  }
+
+  /// Gets the replacement of a character, and whether it was derived from
+  /// the replacement of its lower case letter.
+  fn replacement(&self, c: char) -> Option<(&str, bool)> {
+    let mut lower = c.to_lowercase();
+    let lower = match (lower.next(), lower.next()) {
+      (Some(lower), None) if lower != c => Some(lower),
+      _ => None,
+    };
+    let find = |c: char| {
+      self.replacements
+      .iter()
+      .rev()
+      .find(|&&(k, _)| k == c)
+      .map(|(_, replacement)| replacement.as_str())
+      .or_else(|| {
+        self.locale
+        .iter()
+        .chain(TABLE)
+        .find(|(letters, _)| letters.contains(c))
+        .map(|&(_, replacement)| replacement)
+      })
+    };
+    find(c)
+    .map(|replacement| (replacement, false))
+    .or_else(|| lower.and_then(find).map(|replacement| (replacement, true)))
+  }
+}
+
+/// Checks if a character is a combining diacritical mark, which is dropped.
+#[inline]
+fn is_combining_mark(c: char) -> bool {
+  ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+const GERMAN: &[(&str, &str)] = &[("ä", "ae"), ("ö", "oe"), ("ü", "ue")];
+
+const DANISH: &[(&str, &str)] = &[("æ", "ae"), ("ø", "oe"), ("å", "aa")];
+
+/// Lower case letters and symbols with their ASCII spellings. Upper case
+/// letters are written like their lower case letters.
+const TABLE: &[(&str, &str)] = &[
+  // Latin
+  ("àáâãäåāăąǎǟǡǻȁȃȧạảấầẩẫậắằẳẵặª", "a"), ("æǣǽ", "ae"), ("çćĉċčḉ", "c"), ("ďđðḍḏ", "d"),
+  ("ǆǳ", "dz"), ("èéêëēĕėęěȅȇȩẹẻẽếềểễệ", "e"), ("ƒ", "f"), ("ĝğġģǧǵḡ", "g"), ("ĥħḥḫ", "h"),
+  ("ìíîïĩīĭįıǐȉȋỉị", "i"), ("İ", "I"), ("ĳ", "ij"), ("ĵǰ", "j"), ("ķĸǩ", "k"), ("ĺļľŀłḷ", "l"),
+  ("ǉ", "lj"), ("ḿṁṃ", "m"), ("ñńņňŉŋǹṅṇ", "n"), ("ǌ", "nj"),
+  ("òóôõöøōŏőǒǫǿȍȏȫȭȯȱọỏốồổỗộớờởỡợơº", "o"), ("œ", "oe"), ("ṕṗ", "p"), ("ŕŗřȑȓṛ", "r"),
+  ("śŝşšșſṡṣ", "s"), ("ß", "ss"), ("ţťŧțṭ", "t"), ("þ", "th"), ("ùúûüũūŭůűųǔǖǘǚǜȕȗụủứừửữựư", "u"),
+  ("ṽṿ", "v"), ("ŵẁẃẅ", "w"), ("ẋẍ", "x"), ("ýÿŷȳẏỳỵỷỹ", "y"), ("źżžẓ", "z"),
+  // Greek
+  ("αά", "a"), ("β", "v"), ("γ", "g"), ("δ", "d"), ("εέ", "e"), ("ζ", "z"), ("ηή", "i"), ("θ", "th"),
+  ("ιίϊΐ", "i"), ("κ", "k"), ("λ", "l"), ("μ", "m"), ("ν", "n"), ("ξ", "x"), ("οό", "o"), ("π", "p"),
+  ("ρ", "r"), ("σς", "s"), ("τ", "t"), ("υύϋΰ", "y"), ("φ", "f"), ("χ", "ch"), ("ψ", "ps"),
+  ("ωώ", "o"),
+  // Cyrillic
+  ("а", "a"), ("б", "b"), ("в", "v"), ("гґ", "g"), ("д", "d"), ("еэ", "e"), ("ё", "yo"), ("ж", "zh"),
+  ("з", "z"), ("иі", "i"), ("й", "y"), ("к", "k"), ("л", "l"), ("м", "m"), ("н", "n"), ("о", "o"),
+  ("п", "p"), ("р", "r"), ("с", "s"), ("т", "t"), ("уў", "u"), ("ф", "f"), ("х", "kh"), ("ц", "ts"),
+  ("ч", "ch"), ("ш", "sh"), ("щ", "shch"), ("ъь", ""), ("ы", "y"), ("ю", "yu"), ("я", "ya"),
+  ("ї", "yi"), ("є", "ye"), ("ђ", "dj"), ("ј", "j"), ("љ", "lj"), ("њ", "nj"), ("ћ", "c"),
+  ("ѕџ", "dz"), ("ѓ", "gj"), ("ќ", "kj"),
+  // Symbols
+  ("‘’‚‛′‹›", "'"), ("“”„‟″«»", "\""), ("‐‑‒–—―−", "-"), ("…", "..."), ("•", "*"), ("©", "(c)"),
+  ("®", "(r)"), ("™", "tm"), ("€", "EUR"), ("£", "GBP"), ("¥", "JPY"), ("¢", "c"), ("°", "deg"),
+  ("×", "x"), ("÷", "/"), ("±", "+-"), ("¼", "1/4"), ("½", "1/2"), ("¾", "3/4"), ("¹", "1"),
+  ("²", "2"), ("³", "3"), ("¡", "!"), ("¿", "?"), ("\u{a0}\u{2002}\u{2003}\u{2009}\u{202f}", " "),
+];
+
+#[cfg(test)]
+mod test {
+  use case::{to_pascal_case, to_snake_case};
+  use super::{transliterate, transliterate_with, Transliteration};
+
+  const TEXT: &[(&str, &str)] = &[
+    ("Ångström", "Angstrom"),
+    ("Œuvre ŒUVRE", "Oeuvre OEUVRE"),
+    ("Straße STRAẞE", "Strasse STRASSE"),
+    ("Þór", "Thor"),
+    ("İstanbul ıspanak", "Istanbul ispanak"),
+    ("Tiếng Việt", "Tieng Viet"),
+    ("ΘΕΑΤΡΟ θέατρο", "THEATRO theatro"),
+    ("Αθήνα", "Athina"),
+    ("Жук ЖУК Ж", "Zhuk ZHUK Zh"),
+    ("Їжак", "Yizhak"),
+    ("Объект", "Obekt"),
+    ("e\u{301}te\u{301}", "ete"),
+    ("½ × 3 ± 1°", "1/2 x 3 +- 1deg"),
+    ("a\u{a0}b", "a b"),
+    ("ǅemal", "Dzemal"),
+    ("", ""),
+  ];
+
+  #[test]
+  fn test_transliterate() {
+    for &(text, expected) in TEXT {
+      assert_eq!(transliterate(text), expected);
+    }
+  }
+
+  #[test]
+  fn test_locales() {
+    let german = Transliteration::new().locale("de_CH");
+    assert_eq!(transliterate_with("Ärger ÄRGER Öl", &german), "Aerger AERGER Oel");
+    let norwegian = Transliteration::new().locale("nb-NO");
+    assert_eq!(transliterate_with("Ærø Ål blåbær", &norwegian), "Aeroe Aal blaabaer");
+    assert_eq!(transliterate_with("Ärger", &Transliteration::new().locale("xx")), "Arger");
+  }
+
+  #[test]
+  fn test_cases() {
+    let german = Transliteration::new().locale("de");
+    assert_eq!(to_snake_case(&transliterate_with("Größe", &german)), "groesse");
+    assert_eq!(to_snake_case(&transliterate_with("ÜBER_GRÖSSE", &german)), "ueber_groesse");
+    assert_eq!(to_pascal_case(&transliterate("щука день")), "ShchukaDen");
+  }
+
+  #[test]
+  fn test_replacements() {
+    let options = Transliteration::new().replace('ä', "a").locale("de").fill("");
+    assert_eq!(transliterate_with("Mädchen ☃", &options), "Madchen ");
+    let options = Transliteration::new().replace('ð', "dh");
+    assert_eq!(transliterate_with("Ðað", &options), "Dhadh");
+  }
+}
@@ -0,0 +1,333 @@
+//! Utilities to write numbers as cardinal numbers in words, like “one
+//! thousand two hundred thirty-four,” for cheques and accessible text.
+//!
+//! English is used by default, with the short scale (“billion” is 10⁹) and
+//! without “and.” Configure an `English` instance for the long scale or the
+//! British “and,” or implement `NumberWords` to write numbers in another
+//! language.
+//!
+//! # Example
+//! ```rust
+//! use inflections::words::{decimal_to_words, to_words, to_words_with, English, Scale};
+//!
+//! assert_eq!(to_words(1234), "one thousand two hundred thirty-four".to_owned());
+//! assert_eq!(decimal_to_words("-0.5").unwrap(), "minus zero point five".to_owned());
+//!
+//! let british = English::new().scale(Scale::Long).and(true);
+//! assert_eq!(to_words_with(1_000_000_101, &british), "one thousand million one hundred and one".to_owned());
+//! ```
+
+/// Writes an integer as an English cardinal number in words.
+///
+/// # Example
+/// ```rust
+/// # use inflections::words::to_words;
+/// assert_eq!(to_words(0), "zero".to_owned());
+/// assert_eq!(to_words(13), "thirteen".to_owned());
+/// assert_eq!(to_words(-45), "minus forty-five".to_owned());
+/// assert_eq!(to_words(100_000), "one hundred thousand".to_owned());
+/// assert_eq!(to_words(3_000_000_007), "three billion seven".to_owned());
+/// ```
+pub fn to_words(n: i128) -> String {
+  to_words_with(n, &English::new())
+}
+
+/// Writes an integer as a cardinal number in the words of a `NumberWords`
+/// language.
+pub fn to_words_with(n: i128, words: &dyn NumberWords) -> String {
+  let magnitude = words.integer(&n.unsigned_abs().to_string());
+  if n < 0 { format!("{} {}", words.minus(), magnitude) } else { magnitude }
+}
+
+/// Writes a decimal number like `"-12.50"` as an English cardinal number in
+/// words. Numbers of any magnitude may be written. Returns `None` if the
+/// string is not a decimal number.
+///
+/// # Example
+/// ```rust
+/// # use inflections::words::decimal_to_words;
+/// assert_eq!(decimal_to_words("3.14").unwrap(), "three point one four".to_owned());
+/// assert_eq!(decimal_to_words("12.50").unwrap(), "twelve point five zero".to_owned());
+/// assert_eq!(decimal_to_words("007").unwrap(), "seven".to_owned());
+/// assert_eq!(decimal_to_words("1000000000000000000000000000000000000000000").unwrap(), "one tredecillion".to_owned());
+/// assert!(decimal_to_words("1,000").is_none());
+/// ```
+pub fn decimal_to_words(number: &str) -> Option<String> {
+  decimal_to_words_with(number, &English::new())
+}
+
+/// Writes a decimal number like `"-12.50"` as a cardinal number in the words
+/// of a `NumberWords` language. Returns `None` if the string is not a decimal
+/// number.
+pub fn decimal_to_words_with(number: &str, words: &dyn NumberWords) -> Option<String> {
+  let (negative, unsigned) = match number.strip_prefix('-') {
+    Some(unsigned) => (true, unsigned),
+    None => (false, number.strip_prefix('+').unwrap_or(number)),
+  };
+  let (integer, fraction) = match unsigned.find('.') {
+    Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+    None => (unsigned, None),
+  };
+  let digits = integer.len() + fraction.map_or(0, str::len);
+  if digits == 0 || !integer.chars().chain(fraction.unwrap_or_default().chars()).all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+
+  let integer = integer.trim_start_matches('0');
+  let mut written = words.integer(if integer.is_empty() { "0" } else { integer });
+  if let Some(fraction) = fraction.filter(|fraction| !fraction.is_empty()) {
+    written = format!("{} {} {}", written, words.point(), words.fraction(fraction));
+  }
+  if negative {
+    written = format!("{} {}", words.minus(), written);
+  }
+  Some(written)
+}
+
+/// The cardinal number words of a language.
+pub trait NumberWords {
+  /// Writes the integer with the ASCII `digits`, which have no leading
+  /// zeros unless the integer is zero.
+  fn integer(&self, digits: &str) -> String;
+
+  /// Writes the ASCII `digits` after a decimal point. By default every
+  /// digit is written as an integer.
+  fn fraction(&self, digits: &str) -> String {
+    let digits: Vec<_> = digits.chars().map(|digit| self.integer(&digit.to_string())).collect();
+    digits.join(" ")
+  }
+
+  /// The word written before negative numbers, like “minus.”
+  fn minus(&self) -> &str;
+
+  /// The word written for the decimal point, like “point.”
+  fn point(&self) -> &str;
+}
+
+/// How the names of large numbers like “billion” are assigned to powers of
+/// ten.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Scale {
+  /// Every name is one thousand times the last: a billion is 10⁹.
+  #[default]
+  Short,
+  /// Every name is one million times the last: a billion is 10¹².
+  Long,
+}
+
+/// English cardinal number words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct English {
+  /// How large numbers are named.
+  scale: Scale,
+  /// Whether “and” is written before the tens, as in British English.
+  and: bool,
+}
+
+impl English {
+  /// Creates American English number words, with the short scale and
+  /// without “and.”
+  pub fn new() -> Self {
+    English::default()
+  }
+
+  /// Uses a different scale to name large numbers.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::words::{to_words_with, English, Scale};
+  /// let long = English::new().scale(Scale::Long);
+  /// assert_eq!(to_words_with(2_000_000_000_000, &long), "two billion".to_owned());
+  /// assert_eq!(to_words_with(2_500_000_000, &long), "two thousand five hundred million".to_owned());
+  /// ```
+  pub fn scale(mut self, scale: Scale) -> Self {
+    self.scale = scale;
+    self
+  }
+
+  /// Writes “and” before the tens, as in British English.
+  ///
+  /// # Example
+  /// ```rust
+  /// # use inflections::words::{to_words_with, English};
+  /// let british = English::new().and(true);
+  /// assert_eq!(to_words_with(101, &british), "one hundred and one".to_owned());
+  /// assert_eq!(to_words_with(2_005, &british), "two thousand and five".to_owned());
+  /// assert_eq!(to_words_with(2_100, &british), "two thousand one hundred".to_owned());
+  /// ```
+  pub fn and(mut self, and: bool) -> Self {
+    self.and = and;
+    self
+  }
+
+  /// Writes an integer below one thousand.
+  fn hundreds(&self, n: usize) -> String {
+    let mut words = String::new();
+    if n >= 100 {
+      words.push_str(ONES[n / 100]);
+      words.push_str(" hundred");
+      if n.is_multiple_of(100) {
+        return words;
+      }
+      words.push_str(if self.and { " and " } else { " " });
+    }
+    let rest = n % 100;
+    match rest {
+      0..=19 => words.push_str(ONES[rest]),
+      _ if rest.is_multiple_of(10) => words.push_str(TENS[rest / 10]),
+      _ => {
+        words.push_str(TENS[rest / 10]);
+        words.push('-');
+        words.push_str(ONES[rest % 10]);
+      },
+    }
+    words
+  }
+
+  /// Writes an integer below one million, the long scale’s unit.
+  fn thousands(&self, n: usize) -> String {
+    match (n / 1000, n % 1000) {
+      (0, low) => self.hundreds(low),
+      (high, 0) => format!("{} thousand", self.hundreds(high)),
+      (high, low) => format!("{} thousand {}", self.hundreds(high), self.hundreds(low)),
+    }
+  }
+}
+
+impl NumberWords for English {
+  fn integer(&self, digits: &str) -> String {
+    let (group, names) = match self.scale {
+      Scale::Short => (3, &SHORT_SCALE[..]),
+      Scale::Long => (6, &LONG_SCALE[..]),
+    };
+
+    // Write “and” before the last group, as in “one thousand and one.”
+    let units: usize = digits[digits.len().saturating_sub(3)..].parse().unwrap_or(0);
+    if self.and && digits.len() > 3 && (1..100).contains(&units) {
+      let rounded = format!("{}000", &digits[..digits.len() - 3]);
+      return format!("{} and {}", self.integer(&rounded), self.hundreds(units));
+    }
+
+    // Numbers past the largest name repeat it, as in “one thousand vigintillion.”
+    if digits.len() > group * (names.len() + 1) {
+      let (high, low) = digits.split_at(digits.len() - group * names.len());
+      let low = low.trim_start_matches('0');
+      let high = format!("{} {}", self.integer(high), names[names.len() - 1]);
+      return if low.is_empty() { high } else { format!("{} {}", high, self.integer(low)) };
+    }
+
+    let mut written = Vec::new();
+    let mut end = digits.len();
+    let mut scale = 0;
+    while end > 0 {
+      let start = end.saturating_sub(group);
+      let value: usize = digits[start..end].parse().unwrap_or(0);
+      if value > 0 || digits.len() == 1 {
+        let words = if group == 3 { self.hundreds(value) } else { self.thousands(value) };
+        written.push(if scale == 0 { words } else { format!("{} {}", words, names[scale - 1]) });
+      }
+      end = start;
+      scale += 1;
+    }
+    written.reverse();
+    written.join(" ")
+  }
+
+  fn minus(&self) -> &str {
+    "minus"
+  }
+
+  fn point(&self) -> &str {
+    "point"
+  }
+}
+
+const ONES: [&str; 20] = [
+  "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+  "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+/// Short scale names of the powers of one thousand, from 10³ to 10⁶³.
+const SHORT_SCALE: [&str; 21] = [
+  "thousand", "million", "billion", "trillion", "quadrillion", "quintillion", "sextillion",
+  "septillion", "octillion", "nonillion", "decillion", "undecillion", "duodecillion", "tredecillion",
+  "quattuordecillion", "quindecillion", "sexdecillion", "septendecillion", "octodecillion",
+  "novemdecillion", "vigintillion",
+];
+
+/// Long scale names of the powers of one million, from 10⁶ to 10¹²⁰.
+const LONG_SCALE: [&str; 20] = [
+  "million", "billion", "trillion", "quadrillion", "quintillion", "sextillion", "septillion",
+  "octillion", "nonillion", "decillion", "undecillion", "duodecillion", "tredecillion",
+  "quattuordecillion", "quindecillion", "sexdecillion", "septendecillion", "octodecillion",
+  "novemdecillion", "vigintillion",
+];
+
+#[cfg(test)]
+mod test {
+  use super::{decimal_to_words, to_words, to_words_with, English, Scale};
+
+  #[test]
+  fn test_to_words() {
+    let expected = [
+      (1, "one"), (10, "ten"), (19, "nineteen"), (20, "twenty"), (21, "twenty-one"),
+      (99, "ninety-nine"), (100, "one hundred"), (101, "one hundred one"),
+      (999, "nine hundred ninety-nine"), (1000, "one thousand"), (1001, "one thousand one"),
+      (1_000_000, "one million"), (1_002_003, "one million two thousand three"),
+      (-1_000_000_000_000, "minus one trillion"),
+    ];
+    for &(n, words) in &expected {
+      assert_eq!(to_words(n), words);
+    }
+    assert_eq!(
+      to_words(i128::MIN),
+      "minus one hundred seventy undecillion one hundred forty-one decillion one hundred eighty-three \
+      nonillion four hundred sixty octillion four hundred sixty-nine septillion two hundred thirty-one \
+      sextillion seven hundred thirty-one quintillion six hundred eighty-seven quadrillion three hundred \
+      three trillion seven hundred fifteen billion eight hundred eighty-four million one hundred five \
+      thousand seven hundred twenty-eight",
+    );
+  }
+
+  #[test]
+  fn test_british() {
+    let british = English::new().and(true);
+    assert_eq!(to_words_with(0, &british), "zero");
+    assert_eq!(to_words_with(99, &british), "ninety-nine");
+    assert_eq!(to_words_with(110, &british), "one hundred and ten");
+    assert_eq!(to_words_with(1_000_010, &british), "one million and ten");
+    assert_eq!(to_words_with(1_234_567, &british), "one million two hundred and thirty-four thousand five hundred and sixty-seven");
+  }
+
+  #[test]
+  fn test_long_scale() {
+    let long = English::new().scale(Scale::Long);
+    assert_eq!(to_words_with(1_000_000, &long), "one million");
+    assert_eq!(to_words_with(1_000_000_000, &long), "one thousand million");
+    assert_eq!(to_words_with(1_000_000_000_000_000_000, &long), "one trillion");
+    assert_eq!(to_words_with(123_456_000_001, &long), "one hundred twenty-three thousand four hundred fifty-six million one");
+  }
+
+  #[test]
+  fn test_large_magnitudes() {
+    let vigintillion = format!("1{}", "0".repeat(63));
+    assert_eq!(decimal_to_words(&vigintillion).unwrap(), "one vigintillion");
+    let thousand_vigintillion = format!("1{}1", "0".repeat(65));
+    assert_eq!(decimal_to_words(&thousand_vigintillion).unwrap(), "one thousand vigintillion one");
+  }
+
+  #[test]
+  fn test_decimals() {
+    assert_eq!(decimal_to_words("0").unwrap(), "zero");
+    assert_eq!(decimal_to_words(".25").unwrap(), "zero point two five");
+    assert_eq!(decimal_to_words("+4.").unwrap(), "four");
+    assert_eq!(decimal_to_words("-1.05").unwrap(), "minus one point zero five");
+    assert!(decimal_to_words("").is_none());
+    assert!(decimal_to_words("-").is_none());
+    assert!(decimal_to_words(".").is_none());
+    assert!(decimal_to_words("1.2.3").is_none());
+    assert!(decimal_to_words("1e3").is_none());
+  }
+}